@@ -6,6 +6,33 @@ impl<const VALUE: bool> Const<VALUE> {
     pub fn new() -> Self {
         Default::default()
     }
+
+    /// Return the constant if `value` equals `VALUE`.
+    #[inline(always)]
+    pub fn try_from_value(value: bool) -> Result<Self, crate::ValueMismatch<bool>> {
+        if value == VALUE {
+            Ok(Self::new())
+        } else {
+            Err(crate::ValueMismatch::new(VALUE, value))
+        }
+    }
+
+    /// Return the constant, panicking if `value` does not equal `VALUE`.
+    #[inline(always)]
+    pub fn assert_from(value: bool) -> Self {
+        match Self::try_from_value(value) {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+impl<const VALUE: bool> core::convert::TryFrom<bool> for Const<VALUE> {
+    type Error = crate::ValueMismatch<bool>;
+    #[inline(always)]
+    fn try_from(value: bool) -> Result<Self, Self::Error> {
+        Self::try_from_value(value)
+    }
 }
 
 impl<const VALUE: bool> core::fmt::Display for Const<VALUE> {
@@ -55,6 +82,134 @@ impl<const VALUE: bool> crate::Equals<Const<VALUE>> for bool {
     }
 }
 
+impl<const VALUE: bool> crate::Unify<Const<VALUE>> for Const<VALUE> {
+    type Output = Const<VALUE>;
+    #[inline(always)]
+    fn unify(&self, _: &Const<VALUE>) -> Option<Self::Output> {
+        Some(*self)
+    }
+}
+
+impl crate::Unify<Const<false>> for Const<true> {
+    type Output = bool;
+    #[inline(always)]
+    fn unify(&self, _: &Const<false>) -> Option<Self::Output> {
+        None
+    }
+}
+
+impl crate::Unify<Const<true>> for Const<false> {
+    type Output = bool;
+    #[inline(always)]
+    fn unify(&self, _: &Const<true>) -> Option<Self::Output> {
+        None
+    }
+}
+
+impl<const VALUE: bool> crate::Unify<bool> for Const<VALUE> {
+    type Output = bool;
+    #[inline(always)]
+    fn unify(&self, rhs: &bool) -> Option<Self::Output> {
+        if VALUE == *rhs {
+            Some(VALUE)
+        } else {
+            None
+        }
+    }
+}
+
+impl<const VALUE: bool> crate::Unify<Const<VALUE>> for bool {
+    type Output = bool;
+    #[inline(always)]
+    fn unify(&self, _: &Const<VALUE>) -> Option<Self::Output> {
+        if *self == VALUE {
+            Some(VALUE)
+        } else {
+            None
+        }
+    }
+}
+
+impl crate::Lt<Const<true>> for Const<false> {
+    impl_ordering_check!(Lt, Const<true>, const);
+}
+
+impl<const VALUE: bool> crate::Lt<bool> for Const<VALUE> {
+    impl_ordering_check!(Lt, bool, <);
+}
+
+impl<const VALUE: bool> crate::Lt<Const<VALUE>> for bool {
+    impl_ordering_check!(Lt, Const<VALUE>, <);
+}
+
+impl crate::Gt<Const<false>> for Const<true> {
+    impl_ordering_check!(Gt, Const<false>, const);
+}
+
+impl<const VALUE: bool> crate::Gt<bool> for Const<VALUE> {
+    impl_ordering_check!(Gt, bool, >);
+}
+
+impl<const VALUE: bool> crate::Gt<Const<VALUE>> for bool {
+    impl_ordering_check!(Gt, Const<VALUE>, >);
+}
+
+impl crate::Leq<Const<false>> for Const<false> {
+    impl_ordering_check!(Leq, Const<false>, const);
+}
+
+impl crate::Leq<Const<true>> for Const<false> {
+    impl_ordering_check!(Leq, Const<true>, const);
+}
+
+impl crate::Leq<Const<true>> for Const<true> {
+    impl_ordering_check!(Leq, Const<true>, const);
+}
+
+impl<const VALUE: bool> crate::Leq<bool> for Const<VALUE> {
+    impl_ordering_check!(Leq, bool, <=);
+}
+
+impl<const VALUE: bool> crate::Leq<Const<VALUE>> for bool {
+    impl_ordering_check!(Leq, Const<VALUE>, <=);
+}
+
+impl crate::Geq<Const<false>> for Const<false> {
+    impl_ordering_check!(Geq, Const<false>, const);
+}
+
+impl crate::Geq<Const<false>> for Const<true> {
+    impl_ordering_check!(Geq, Const<false>, const);
+}
+
+impl crate::Geq<Const<true>> for Const<true> {
+    impl_ordering_check!(Geq, Const<true>, const);
+}
+
+impl<const VALUE: bool> crate::Geq<bool> for Const<VALUE> {
+    impl_ordering_check!(Geq, bool, >=);
+}
+
+impl<const VALUE: bool> crate::Geq<Const<VALUE>> for bool {
+    impl_ordering_check!(Geq, Const<VALUE>, >=);
+}
+
+impl crate::NotEquals<Const<true>> for Const<false> {
+    impl_ordering_check!(NotEquals::check_distinct, Const<true>, const);
+}
+
+impl crate::NotEquals<Const<false>> for Const<true> {
+    impl_ordering_check!(NotEquals::check_distinct, Const<false>, const);
+}
+
+impl<const VALUE: bool> crate::NotEquals<bool> for Const<VALUE> {
+    impl_ordering_check!(NotEquals::check_distinct, bool, !=);
+}
+
+impl<const VALUE: bool> crate::NotEquals<Const<VALUE>> for bool {
+    impl_ordering_check!(NotEquals::check_distinct, Const<VALUE>, !=);
+}
+
 impl<const VALUE1: bool, const VALUE2: bool> core::cmp::PartialEq<Const<VALUE2>> for Const<VALUE1> {
     fn eq(&self, _: &Const<VALUE2>) -> bool { VALUE1 == VALUE2 }
 }
@@ -79,12 +234,159 @@ impl<const VALUE: bool> core::cmp::PartialOrd<Const<VALUE>> for bool {
     fn partial_cmp(&self, _: &Const<VALUE>) -> Option<core::cmp::Ordering> { self.partial_cmp(&VALUE) }
 }
 
+impl<const VALUE: bool> core::cmp::Eq for Const<VALUE> {}
+
+impl<const VALUE: bool> core::cmp::Ord for Const<VALUE> {
+    fn cmp(&self, _: &Const<VALUE>) -> core::cmp::Ordering { core::cmp::Ordering::Equal }
+}
+
+impl<const VALUE: bool> core::hash::Hash for Const<VALUE> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) { VALUE.hash(state) }
+}
+
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl_binary_op!(@const $trait, $method, $op, false, false);
+        impl_binary_op!(@const $trait, $method, $op, false, true);
+        impl_binary_op!(@const $trait, $method, $op, true, false);
+        impl_binary_op!(@const $trait, $method, $op, true, true);
+
+        impl<const VALUE: bool> core::ops::$trait<bool> for Const<VALUE> {
+            type Output = bool;
+            #[inline(always)]
+            fn $method(self, rhs: bool) -> bool {
+                VALUE $op rhs
+            }
+        }
+
+        impl<const VALUE: bool> core::ops::$trait<Const<VALUE>> for bool {
+            type Output = bool;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE>) -> bool {
+                self $op VALUE
+            }
+        }
+    };
+    (@const $trait:ident, $method:ident, $op:tt, $lhs:tt, $rhs:tt) => {
+        impl core::ops::$trait<Const<$rhs>> for Const<$lhs> {
+            type Output = Const<{ $lhs $op $rhs }>;
+            #[inline(always)]
+            fn $method(self, _: Const<$rhs>) -> Self::Output {
+                Const::new()
+            }
+        }
+    };
+}
+
+impl_binary_op!(BitAnd, bitand, &);
+impl_binary_op!(BitOr, bitor, |);
+impl_binary_op!(BitXor, bitxor, ^);
+
+macro_rules! impl_logic_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl_logic_op!(@const $trait, $method, $op, false, false);
+        impl_logic_op!(@const $trait, $method, $op, false, true);
+        impl_logic_op!(@const $trait, $method, $op, true, false);
+        impl_logic_op!(@const $trait, $method, $op, true, true);
+
+        impl<const VALUE: bool> $trait<bool> for Const<VALUE> {
+            type Output = bool;
+            #[inline(always)]
+            fn $method(self, rhs: bool) -> bool {
+                VALUE $op rhs
+            }
+        }
+
+        impl<const VALUE: bool> $trait<Const<VALUE>> for bool {
+            type Output = bool;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE>) -> bool {
+                self $op VALUE
+            }
+        }
+    };
+    (@const $trait:ident, $method:ident, $op:tt, $lhs:tt, $rhs:tt) => {
+        impl $trait<Const<$rhs>> for Const<$lhs> {
+            type Output = Const<{ $lhs $op $rhs }>;
+            #[inline(always)]
+            fn $method(self, _: Const<$rhs>) -> Self::Output {
+                Const::new()
+            }
+        }
+    };
+}
+
+impl_logic_op!(And, and, &&);
+impl_logic_op!(Or, or, ||);
+impl_logic_op!(Xor, xor, ^);
+// `a <= b` is `!a || b` for bool.
+impl_logic_op!(Implies, implies, <=);
+
+#[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+impl IsTrue for Const<true> {}
+
+// With `generic_const_exprs`, an impl for `Const<true>` alone makes rustc discard the candidates bounded by
+// `Assert<{ expr }>: IsTrue` before `expr` is known, which breaks type inference in downstream crates.
+// This impl keeps such a bound ambiguous until `expr` is evaluated, and a false `expr` fails in `is_true`,
+// so the `on_unimplemented` message of `IsTrue` is not used with the `incomplete` feature.
+#[rustversion::nightly]
+#[cfg(feature = "incomplete")]
+const fn is_true(cond: bool) -> usize {
+    assert!(cond, "compile-time assertion failed");
+    0
+}
+
+#[rustversion::nightly]
+#[cfg(feature = "incomplete")]
+impl<const VALUE: bool> IsTrue for Const<VALUE> where [(); is_true(VALUE)]: Sized {}
+
+impl<A, B> Select<A, B> for Const<true> {
+    type Output = A;
+    #[inline(always)]
+    fn select(self, a: A, _: B) -> A {
+        a
+    }
+    #[inline(always)]
+    fn select_with<FA: FnOnce() -> A, FB: FnOnce() -> B>(self, a: FA, _: FB) -> A {
+        a()
+    }
+}
+
+impl<A, B> Select<A, B> for Const<false> {
+    type Output = B;
+    #[inline(always)]
+    fn select(self, _: A, b: B) -> B {
+        b
+    }
+    #[inline(always)]
+    fn select_with<FA: FnOnce() -> A, FB: FnOnce() -> B>(self, _: FA, b: FB) -> B {
+        b()
+    }
+}
+
+impl core::ops::Not for Const<false> {
+    type Output = Const<true>;
+    #[inline(always)]
+    fn not(self) -> Const<true> {
+        Const::new()
+    }
+}
+
+impl core::ops::Not for Const<true> {
+    type Output = Const<false>;
+    #[inline(always)]
+    fn not(self) -> Const<false> {
+        Const::new()
+    }
+}
+
 #[cfg(test)]
 mod test_nightly {
     use super::*;
 
     #[test]
     fn test_equals() {
+        #[allow(clippy::multiple_bound_locations)]
         fn test<A: crate::MayBeConst<bool>, B: crate::MayBeConst<bool>>() where A: crate::Equals<B> {}
         test::<Const<false>, Const<false>>();
         test::<Const<false>, bool>();
@@ -94,6 +396,7 @@ mod test_nightly {
 
     #[test]
     fn test_equals_result_const() {
+        #[allow(clippy::multiple_bound_locations)]
         fn const_side_is_const<A: crate::MayBeConst<bool>, B: crate::MayBeConst<bool>>() -> bool where A: crate::Equals<B> {
             <<A as crate::Equals<B>>::ConstSide as crate::MayBeConstAT>::IS_CONST
         }
@@ -114,6 +417,45 @@ mod test_nightly {
     }
 
     #[test]
+    fn test_unify() {
+        use crate::Unify;
+        #[allow(clippy::multiple_bound_locations)]
+        fn output_is_const<A: crate::MayBeConst<bool>, B: crate::MayBeConst<bool>>() -> bool where A: Unify<B> {
+            <<A as Unify<B>>::Output as crate::MayBeConstAT>::IS_CONST
+        }
+        assert!(output_is_const::<Const<false>, Const<false>>());
+        assert!(output_is_const::<Const<true>, Const<true>>());
+        assert!(!output_is_const::<Const<true>, Const<false>>());
+        assert!(!output_is_const::<Const<false>, bool>());
+        assert!(!output_is_const::<bool, Const<false>>());
+
+        assert_eq!(Const::<true>::new().unify(&Const::<true>::new()), Some(Const::<true>::new()));
+        assert_eq!(Const::<true>::new().unify(&Const::<false>::new()), None);
+        assert_eq!(Const::<false>::new().unify(&Const::<true>::new()), None);
+        assert_eq!(Const::<true>::new().unify(&true), Some(true));
+        assert_eq!(Const::<true>::new().unify(&false), None);
+        assert_eq!(false.unify(&Const::<false>::new()), Some(false));
+        assert_eq!(true.unify(&Const::<false>::new()), None);
+    }
+
+    #[test]
+    fn test_try_from_value() {
+        use core::convert::TryFrom;
+        assert_eq!(Const::<true>::try_from_value(true), Ok(Const::<true>::new()));
+        assert_eq!(Const::<true>::try_from_value(false), Err(crate::ValueMismatch::new(true, false)));
+        assert_eq!(Const::<true>::try_from(true), Ok(Const::<true>::new()));
+        assert_eq!(Const::<true>::try_from(false), Err(crate::ValueMismatch::new(true, false)));
+        assert_eq!(Const::<true>::assert_from(true), Const::<true>::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_from() {
+        Const::<true>::assert_from(false);
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
     fn test_is_const() {
         assert!(<Const<false> as crate::MayBeConstAT>::IS_CONST);
     }
@@ -131,6 +473,33 @@ mod test_nightly {
         assert!(!true.eq(&false));
     }
 
+    #[test]
+    fn test_ord() {
+        assert_eq!(Const::<true>::new().cmp(&Const::<true>::new()), core::cmp::Ordering::Equal);
+        let mut values = [Const::<true>::new(); 2];
+        values.sort();
+        assert_eq!(values.iter().max(), Some(&Const::<true>::new()));
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::hash::{Hash, Hasher};
+        fn hash<T: Hash>(value: &T) -> u64 {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+        assert_eq!(hash(&Const::<false>::new()), hash(&false));
+        assert_eq!(hash(&Const::<true>::new()), hash(&true));
+
+        let mut set = std::collections::HashSet::new();
+        assert!(set.insert(Const::<true>::new()));
+        assert!(!set.insert(Const::<true>::new()));
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(Const::<true>::new(), ());
+        assert!(map.contains_key(&Const::<true>::new()));
+    }
+
     #[test]
     fn test_partial_ord() {
         assert_eq!(Const::<true>::new().partial_cmp(&Const::<true>::new()), Some(core::cmp::Ordering::Equal));
@@ -148,4 +517,198 @@ mod test_nightly {
         assert_eq!(false.partial_cmp(&Const::<true>::new()), Some(core::cmp::Ordering::Less));
         assert_eq!(false.partial_cmp(&true), Some(core::cmp::Ordering::Less));
     }
+
+    #[test]
+    fn test_bitwise() {
+        let _: Const<false> = Const::<false>::new() & Const::<true>::new();
+        let _: Const<true> = Const::<true>::new() & Const::<true>::new();
+        assert!(!(Const::<true>::new() & false));
+        assert!(true & Const::<true>::new());
+
+        let _: Const<false> = Const::<false>::new() | Const::<false>::new();
+        let _: Const<true> = Const::<false>::new() | Const::<true>::new();
+        assert!(Const::<false>::new() | true);
+        assert!(!(false | Const::<false>::new()));
+
+        let _: Const<false> = Const::<true>::new() ^ Const::<true>::new();
+        let _: Const<true> = Const::<true>::new() ^ Const::<false>::new();
+        assert!(Const::<true>::new() ^ false);
+        assert!(!(true ^ Const::<true>::new()));
+
+        let _: Const<true> = !Const::<false>::new();
+        let _: Const<false> = !Const::<true>::new();
+    }
+
+    #[test]
+    fn test_logic_ops() {
+        use super::{And, Implies, Or, Xor};
+        let _: Const<false> = Const::<true>::new().and(Const::<false>::new());
+        let _: Const<true> = Const::<true>::new().and(Const::<true>::new());
+        assert!(Const::<true>::new().and(true));
+        assert!(!false.and(Const::<true>::new()));
+
+        let _: Const<false> = Const::<false>::new().or(Const::<false>::new());
+        let _: Const<true> = Const::<false>::new().or(Const::<true>::new());
+        assert!(Const::<false>::new().or(true));
+        assert!(!false.or(Const::<false>::new()));
+
+        let _: Const<false> = Const::<true>::new().xor(Const::<true>::new());
+        let _: Const<true> = Const::<true>::new().xor(Const::<false>::new());
+        assert!(Const::<true>::new().xor(false));
+        assert!(!true.xor(Const::<true>::new()));
+
+        let _: Const<true> = Const::<false>::new().implies(Const::<false>::new());
+        let _: Const<true> = Const::<false>::new().implies(Const::<true>::new());
+        let _: Const<false> = Const::<true>::new().implies(Const::<false>::new());
+        let _: Const<true> = Const::<true>::new().implies(Const::<true>::new());
+        assert!(Const::<false>::new().implies(false));
+        assert!(!true.implies(Const::<false>::new()));
+    }
+
+    #[test]
+    fn test_logic_output_is_const() {
+        fn output_is_const<A: super::Implies<B>, B: crate::MayBeConst<bool>>() -> bool {
+            <A::Output as crate::MayBeConstAT>::IS_CONST
+        }
+        assert!(output_is_const::<Const<true>, Const<false>>());
+        assert!(!output_is_const::<Const<true>, bool>());
+        assert!(!output_is_const::<bool, Const<true>>());
+        assert!(!output_is_const::<bool, bool>());
+    }
+
+    #[test]
+    fn test_is_true() {
+        fn test<A: IsTrue>() {}
+        test::<Const<true>>();
+        test::<crate::Assert<{ 16 % 8 == 0 }>>();
+        // test::<Const<false>>(); // <-Compile Error!
+        // test::<crate::Assert<{ 12 % 8 == 0 }>>(); // <-Compile Error!
+    }
+
+    #[test]
+    fn test_select() {
+        use super::{select, select_with};
+        let value: u8 = select(Const::<true>::new(), 1u8, 'b');
+        assert_eq!(value, 1);
+        let value: char = select(Const::<false>::new(), 1u8, 'b');
+        assert_eq!(value, 'b');
+        let value: u8 = select_with(Const::<true>::new(), || 1u8, || -> char { unreachable!() });
+        assert_eq!(value, 1);
+        let value: char = select_with(Const::<false>::new(), || -> u8 { unreachable!() }, || 'b');
+        assert_eq!(value, 'b');
+
+        let value: u8 = select(Const::<true>::new().and(Const::<true>::new()), 1u8, 'b');
+        assert_eq!(value, 1);
+    }
+
+    #[test]
+    fn test_bitwise_output_is_const() {
+        fn output_is_const<A: core::ops::BitAnd<B>, B>() -> bool where A::Output: crate::MayBeConstAT {
+            <A::Output as crate::MayBeConstAT>::IS_CONST
+        }
+        assert!(output_is_const::<Const<true>, Const<false>>());
+        assert!(!output_is_const::<Const<true>, bool>());
+        assert!(!output_is_const::<bool, Const<true>>());
+        assert!(!output_is_const::<bool, bool>());
+    }
+}
+
+#[cfg(test)]
+mod test_ord {
+    use super::*;
+
+    #[test]
+    fn test_lt() {
+        #[allow(clippy::multiple_bound_locations)]
+        fn test<A: crate::MayBeConst<bool>, B: crate::MayBeConst<bool>>() where A: crate::Lt<B> {}
+        test::<Const<false>, Const<true>>();
+        test::<Const<false>, bool>();
+        test::<bool, Const<false>>();
+        test::<bool, bool>();
+        // test::<Const<false>, Const<false>>(); // <-Compile Error!
+        // test::<Const<true>, Const<false>>(); // <-Compile Error!
+    }
+
+    #[test]
+    fn test_gt() {
+        #[allow(clippy::multiple_bound_locations)]
+        fn test<A: crate::MayBeConst<bool>, B: crate::MayBeConst<bool>>() where A: crate::Gt<B> {}
+        test::<Const<true>, Const<false>>();
+        test::<Const<false>, bool>();
+        test::<bool, Const<false>>();
+        test::<bool, bool>();
+        // test::<Const<false>, Const<false>>(); // <-Compile Error!
+        // test::<Const<false>, Const<true>>(); // <-Compile Error!
+    }
+
+    #[test]
+    fn test_leq() {
+        #[allow(clippy::multiple_bound_locations)]
+        fn test<A: crate::MayBeConst<bool>, B: crate::MayBeConst<bool>>() where A: crate::Leq<B> {}
+        test::<Const<false>, Const<false>>();
+        test::<Const<false>, Const<true>>();
+        test::<Const<false>, bool>();
+        test::<bool, Const<false>>();
+        test::<bool, bool>();
+        // test::<Const<true>, Const<false>>(); // <-Compile Error!
+    }
+
+    #[test]
+    fn test_not_equals() {
+        #[allow(clippy::multiple_bound_locations)]
+        fn test<A: crate::MayBeConst<bool>, B: crate::MayBeConst<bool>>() where A: crate::NotEquals<B> {}
+        test::<Const<false>, Const<true>>();
+        test::<Const<true>, Const<false>>();
+        test::<Const<true>, bool>();
+        test::<bool, Const<true>>();
+        test::<bool, bool>();
+        // test::<Const<true>, Const<true>>(); // <-Compile Error!
+    }
+
+    #[test]
+    fn test_check_distinct() {
+        use crate::NotEquals;
+        let proof = Const::<false>::new().check_distinct(&Const::<true>::new()).unwrap();
+        assert!(proof.lhs_is_const() && proof.rhs_is_const());
+        assert!(Const::<false>::new().check_distinct(&true).is_ok());
+        assert!(Const::<true>::new().check_distinct(&true).is_err());
+        assert!(false.check_distinct(&Const::<true>::new()).is_ok());
+        assert!(false.check_distinct(&Const::<false>::new()).is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        use crate::{Lt, Gt, Leq, Geq};
+        let proof = Lt::check(&Const::<false>::new(), &Const::<true>::new()).unwrap();
+        assert!(proof.lhs_is_const() && proof.rhs_is_const());
+        let proof = Lt::check(&Const::<false>::new(), &true).unwrap();
+        assert!(proof.lhs_is_const() && !proof.rhs_is_const());
+        let proof = Lt::check(&false, &Const::<true>::new()).unwrap();
+        assert!(!proof.lhs_is_const() && proof.rhs_is_const());
+
+        assert!(Lt::check(&Const::<true>::new(), &true).is_err());
+        assert!(Gt::check(&Const::<true>::new(), &Const::<false>::new()).is_ok());
+        assert!(Gt::check(&false, &Const::<false>::new()).is_err());
+        assert!(Leq::check(&Const::<true>::new(), &Const::<true>::new()).is_ok());
+        assert!(Leq::check(&Const::<true>::new(), &false).is_err());
+        assert!(Geq::check(&Const::<false>::new(), &Const::<false>::new()).is_ok());
+        assert!(Geq::check(&false, &Const::<true>::new()).is_err());
+
+        let error = Gt::check(&Const::<false>::new(), &true).unwrap_err();
+        assert!(!*error.lhs());
+        assert!(*error.rhs());
+        assert_eq!(error.to_string(), "false > true is not satisfied");
+    }
+
+    #[test]
+    fn test_geq() {
+        #[allow(clippy::multiple_bound_locations)]
+        fn test<A: crate::MayBeConst<bool>, B: crate::MayBeConst<bool>>() where A: crate::Geq<B> {}
+        test::<Const<false>, Const<false>>();
+        test::<Const<true>, Const<false>>();
+        test::<Const<false>, bool>();
+        test::<bool, Const<false>>();
+        test::<bool, bool>();
+        // test::<Const<false>, Const<true>>(); // <-Compile Error!
+    }
 }
@@ -1,6 +1,3 @@
 include!("stable.rs");
 #[rustversion::since(1.51)]
 include!("min.rs");
-#[rustversion::all(since(1.51), nightly)]
-#[cfg(feature = "incomplete")]
-include!("incomplete.rs");
@@ -18,15 +18,327 @@ impl crate::Equals<bool> for bool {
     }
 }
 
+impl crate::Unify<bool> for bool {
+    type Output = bool;
+    #[inline(always)]
+    fn unify(&self, rhs: &bool) -> Option<Self::Output> {
+        if *self == *rhs {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+}
+
+impl crate::Lt<bool> for bool {
+    impl_ordering_check!(Lt, bool, <);
+}
+
+impl crate::Gt<bool> for bool {
+    impl_ordering_check!(Gt, bool, >);
+}
+
+impl crate::Leq<bool> for bool {
+    impl_ordering_check!(Leq, bool, <=);
+}
+
+impl crate::Geq<bool> for bool {
+    impl_ordering_check!(Geq, bool, >=);
+}
+
+impl crate::NotEquals<bool> for bool {
+    impl_ordering_check!(NotEquals::check_distinct, bool, !=);
+}
+
+/// Logical AND of two values which may or may not be constants.
+/// [And::Output] is a constant if both sides are constants.
+///
+/// # Example
+/// ```
+/// # #[rustversion::since(1.51)] fn test() {
+/// use partial_const::bool::{And, Const};
+/// use partial_const::MayBeConstAT;
+///
+/// struct Flags<Fast, Safe>(Fast, Safe);
+/// impl<Fast: And<Safe>, Safe: partial_const::MayBeConst<bool>> Flags<Fast, Safe> {
+///     fn fast_and_safe(self) -> Fast::Output {
+///         self.0.and(self.1)
+///     }
+/// }
+///
+/// let _: Const<false> = Flags(Const::<true>::new(), Const::<false>::new()).fast_and_safe();
+/// let _: Const<true> = Flags(Const::<true>::new(), Const::<true>::new()).fast_and_safe();
+/// assert!(Flags(Const::<true>::new(), true).fast_and_safe().value());
+/// # }
+/// # #[rustversion::not(since(1.51))] fn test(){}
+/// # test();
+/// ```
+pub trait And<Rhs: crate::MayBeConst<bool>>: crate::MayBeConst<bool> {
+    type Output: crate::MayBeConst<bool>;
+    fn and(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Logical OR of two values which may or may not be constants.
+/// [Or::Output] is a constant if both sides are constants.
+pub trait Or<Rhs: crate::MayBeConst<bool>>: crate::MayBeConst<bool> {
+    type Output: crate::MayBeConst<bool>;
+    fn or(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Logical XOR of two values which may or may not be constants.
+/// [Xor::Output] is a constant if both sides are constants.
+pub trait Xor<Rhs: crate::MayBeConst<bool>>: crate::MayBeConst<bool> {
+    type Output: crate::MayBeConst<bool>;
+    fn xor(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Logical implication (`!self || rhs`) of two values which may or may not be constants.
+/// [Implies::Output] is a constant if both sides are constants.
+pub trait Implies<Rhs: crate::MayBeConst<bool>>: crate::MayBeConst<bool> {
+    type Output: crate::MayBeConst<bool>;
+    fn implies(self, rhs: Rhs) -> Self::Output;
+}
+
+impl And<bool> for bool {
+    type Output = bool;
+    #[inline(always)]
+    fn and(self, rhs: bool) -> bool {
+        self && rhs
+    }
+}
+
+impl Or<bool> for bool {
+    type Output = bool;
+    #[inline(always)]
+    fn or(self, rhs: bool) -> bool {
+        self || rhs
+    }
+}
+
+impl Xor<bool> for bool {
+    type Output = bool;
+    #[inline(always)]
+    fn xor(self, rhs: bool) -> bool {
+        self ^ rhs
+    }
+}
+
+impl Implies<bool> for bool {
+    type Output = bool;
+    #[inline(always)]
+    fn implies(self, rhs: bool) -> bool {
+        !self || rhs
+    }
+}
+
+/// A runtime choice between two values, produced by [Select] when the condition is not a constant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Either<A, B> {
+    /// The condition was `true`.
+    Left(A),
+    /// The condition was `false`.
+    Right(B),
+}
+
+impl<A, B> Either<A, B> {
+    pub fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+
+    pub fn is_right(&self) -> bool {
+        matches!(self, Either::Right(_))
+    }
+
+    pub fn left(self) -> Option<A> {
+        match self {
+            Either::Left(a) => Some(a),
+            Either::Right(_) => None,
+        }
+    }
+
+    pub fn right(self) -> Option<B> {
+        match self {
+            Either::Left(_) => None,
+            Either::Right(b) => Some(b),
+        }
+    }
+}
+
+impl<T> Either<T, T> {
+    /// Return the value whichever side it is.
+    pub fn into_inner(self) -> T {
+        match self {
+            Either::Left(value) | Either::Right(value) => value,
+        }
+    }
+}
+
+/// A type-level `if` on a condition which may or may not be a constant.
+///
+/// For [struct@Const] conditions, [Select::Output] is `A` or `B` and no branch is left at runtime.
+/// For a runtime `bool`, it is [Either].
+///
+/// # Example
+/// ```
+/// # #[rustversion::since(1.51)] fn test() {
+/// use partial_const::bool::{select, Const, Either, Select};
+///
+/// fn describe<Cond: Select<&'static str, u32>>(cond: Cond) -> Cond::Output {
+///     select(cond, "name", 42)
+/// }
+///
+/// let _: &str = describe(Const::<true>::new());
+/// let _: u32 = describe(Const::<false>::new());
+/// assert_eq!(describe(false), Either::Right(42));
+/// # }
+/// # #[rustversion::not(since(1.51))] fn test(){}
+/// # test();
+/// ```
+pub trait Select<A, B>: crate::MayBeConst<bool> {
+    type Output;
+    /// Choose `a` if the condition is `true`, and `b` otherwise.
+    fn select(self, a: A, b: B) -> Self::Output;
+    /// Like [Select::select], but only the chosen side is evaluated.
+    fn select_with<FA: FnOnce() -> A, FB: FnOnce() -> B>(self, a: FA, b: FB) -> Self::Output;
+}
+
+impl<A, B> Select<A, B> for bool {
+    type Output = Either<A, B>;
+    #[inline(always)]
+    fn select(self, a: A, b: B) -> Either<A, B> {
+        if self {
+            Either::Left(a)
+        } else {
+            Either::Right(b)
+        }
+    }
+    #[inline(always)]
+    fn select_with<FA: FnOnce() -> A, FB: FnOnce() -> B>(self, a: FA, b: FB) -> Either<A, B> {
+        if self {
+            Either::Left(a())
+        } else {
+            Either::Right(b())
+        }
+    }
+}
+
+/// Choose `a` if `cond` is `true`, and `b` otherwise. See [Select].
+#[inline(always)]
+pub fn select<Cond: Select<A, B>, A, B>(cond: Cond, a: A, b: B) -> Cond::Output {
+    cond.select(a, b)
+}
+
+/// Choose `a()` if `cond` is `true`, and `b()` otherwise. Only the chosen side is evaluated. See [Select].
+#[inline(always)]
+pub fn select_with<Cond: Select<A, B>, A, B, FA: FnOnce() -> A, FB: FnOnce() -> B>(cond: Cond, a: FA, b: FB) -> Cond::Output {
+    cond.select_with(a, b)
+}
+
+/// A trait for writing compile-time assertions as `Assert<{ expr }>: IsTrue`.
+///
+/// With the `generic_const_exprs` feature, `expr` may depend on generic constants,
+/// e.g. `where Assert<{ N % 8 == 0 }>: IsTrue`.
+/// The incomplete tier of the ordering traits such as [crate::Lt] is built on it.
+///
+/// Without the `incomplete` feature, `Const<true>` is the only implementor, and a false assertion is reported as an unsatisfied bound.
+/// With it, every `Const<VALUE>` implements it under a bound which fails to evaluate for `false`,
+/// so a false assertion is reported as the constant evaluation error "compile-time assertion failed" instead.
+///
+/// # Example
+/// ```
+/// # #[rustversion::since(1.51)] fn test() {
+/// use partial_const::{Assert, IsTrue};
+///
+/// fn aligned() where Assert<{ 16 % 8 == 0 }>: IsTrue {}
+/// aligned();
+/// // fn misaligned() where Assert<{ 12 % 8 == 0 }>: IsTrue {} <- Compile Error
+/// # }
+/// # #[rustversion::not(since(1.51))] fn test(){}
+/// # test();
+/// ```
+#[rustversion::attr(since(1.78), diagnostic::on_unimplemented(
+    message = "compile-time assertion failed",
+    label = "this assertion is `false`",
+    note = "`Assert<{{ expr }}>: IsTrue` requires `expr` to evaluate to `true`"
+))]
+pub trait IsTrue {}
+
 #[cfg(test)]
 mod test_stable {
     #[test]
     fn test_equals() {
+        #[allow(clippy::multiple_bound_locations)]
         fn test<A: crate::MayBeConst<bool>, B: crate::MayBeConst<bool>>() where A: crate::Equals<B> {}
         test::<bool, bool>();
     }
 
     #[test]
+    fn test_unify() {
+        use crate::Unify;
+        assert_eq!(true.unify(&true), Some(true));
+        assert_eq!(true.unify(&false), None);
+    }
+
+    #[test]
+    fn test_ordering() {
+        #[allow(clippy::multiple_bound_locations)]
+        fn test<A: crate::MayBeConst<bool>, B: crate::MayBeConst<bool>>() where A: crate::Lt<B> + crate::Gt<B> + crate::Leq<B> + crate::Geq<B> {}
+        test::<bool, bool>();
+    }
+
+    #[test]
+    fn test_ordering_check() {
+        use crate::{Lt, Gt, Leq, Geq};
+        assert!(Lt::check(&false, &true).is_ok());
+        assert!(Lt::check(&true, &true).is_err());
+        assert!(Gt::check(&true, &false).is_ok());
+        assert!(Gt::check(&true, &true).is_err());
+        assert!(Leq::check(&true, &true).is_ok());
+        assert!(Leq::check(&true, &false).is_err());
+        assert!(Geq::check(&true, &true).is_ok());
+        assert!(Geq::check(&false, &true).is_err());
+    }
+
+    #[test]
+    fn test_not_equals() {
+        use crate::NotEquals;
+        #[allow(clippy::multiple_bound_locations)]
+        fn test<A: crate::MayBeConst<bool>, B: crate::MayBeConst<bool>>() where A: crate::NotEquals<B> {}
+        test::<bool, bool>();
+        assert!(false.check_distinct(&true).is_ok());
+        assert!(true.check_distinct(&true).is_err());
+    }
+
+    #[test]
+    fn test_logic_ops() {
+        use super::{And, Implies, Or, Xor};
+        assert!(true.and(true));
+        assert!(!true.and(false));
+        assert!(true.or(false));
+        assert!(!false.or(false));
+        assert!(true.xor(false));
+        assert!(!true.xor(true));
+        assert!(false.implies(false));
+        assert!(!true.implies(false));
+    }
+
+    #[test]
+    fn test_select() {
+        use super::{select, select_with, Either};
+        assert_eq!(select(true, 1u8, 'b'), Either::Left(1u8));
+        assert_eq!(select(false, 1u8, 'b'), Either::Right('b'));
+        assert_eq!(select_with(true, || 1u8, || unreachable!()), Either::<u8, char>::Left(1u8));
+        assert_eq!(select_with(false, || unreachable!(), || 'b'), Either::<u8, char>::Right('b'));
+
+        let either: Either<u8, char> = select(true, 1u8, 'b');
+        assert!(either.is_left() && !either.is_right());
+        assert_eq!(either.left(), Some(1));
+        assert_eq!(either.right(), None);
+        assert_eq!(select(false, 1u8, 2u8).into_inner(), 2);
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
     fn test_is_const() {
         assert!(!<bool as crate::MayBeConstAT>::IS_CONST);
     }
@@ -1,130 +1,92 @@
-/// A trait for putting less-than constraints on constants.
-/// It will be implemented if the constant is less than Rhs.
-///
-/// # Example
-/// ```
-/// # #[cfg(feature = "u8")] fn test() {
-/// fn lt<A: partial_const::MayBeConst<u8>, B: partial_const::MayBeConst<u8>>(a: A, b: B) -> bool
-///     where A: partial_const::Lt<B> {
-///     a.value() < b.value()
-/// }
-///
-/// assert!(lt(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<2>::new()));
-/// assert!(lt(partial_const::ConstU8::<1>::new(), 2));
-/// assert!(!lt(partial_const::ConstU8::<1>::new(), 1));
-/// // assert!(lt(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<1>::new())); <- Compile Error
-/// // assert!(lt(partial_const::ConstU8::<2>::new(), partial_const::ConstU8::<1>::new())); <- Compile Error
-/// # }
-/// # #[cfg(feature = "u8")] test();
-/// ```
-pub trait Lt<Rhs> {}
-
-/// A trait for putting greater-than constraints on constants.
-/// It will be implemented if the constant is greater than Rhs.
-///
-/// # Example
-/// ```
-/// # #[cfg(feature = "u8")] fn test() {
-/// fn gt<A: partial_const::MayBeConst<u8>, B: partial_const::MayBeConst<u8>>(a: A, b: B) -> bool
-///     where A: partial_const::Gt<B> {
-///     a.value() > b.value()
-/// }
-///
-/// assert!(gt(partial_const::ConstU8::<2>::new(), partial_const::ConstU8::<1>::new()));
-/// assert!(gt(partial_const::ConstU8::<2>::new(), 1));
-/// assert!(!gt(partial_const::ConstU8::<1>::new(), 1));
-/// // assert!(gt(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<1>::new())); <- Compile Error
-/// // assert!(gt(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<2>::new())); <- Compile Error
-/// # }
-/// # #[cfg(feature = "u8")] test();
-/// ```
-pub trait Gt<Rhs> {}
-
-/// A trait for putting less-than-or-equal constraints on constants.
-/// It will be implemented if the constant is less than or equal Rhs.
-///
-/// # Example
-/// ```
-/// # #[cfg(feature = "u8")] fn test() {
-/// fn leq<A: partial_const::MayBeConst<u8>, B: partial_const::MayBeConst<u8>>(a: A, b: B) -> bool
-///     where A: partial_const::Leq<B> {
-///     a.value() <= b.value()
-/// }
-///
-/// assert!(leq(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<2>::new()));
-/// assert!(leq(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<1>::new()));
-/// assert!(leq(partial_const::ConstU8::<1>::new(), 1));
-/// assert!(!leq(partial_const::ConstU8::<2>::new(), 1));
-/// // assert!(leq(partial_const::ConstU8::<2>::new(), partial_const::ConstU8::<1>::new())); <- Compile Error
-/// # }
-/// # #[cfg(feature = "u8")] test();
-/// ```
-pub trait Leq<Rhs> {}
-
-/// A trait for putting greater-than-or-equal constraints on constants.
-/// It will be implemented if the constant is greater than or equal Rhs.
-///
-/// # Example
-/// ```
-/// # #[cfg(feature = "u8")] fn test() {
-/// fn geq<A: partial_const::MayBeConst<u8>, B: partial_const::MayBeConst<u8>>(a: A, b: B) -> bool
-///     where A: partial_const::Geq<B> {
-///     a.value() >= b.value()
-/// }
-///
-/// assert!(geq(partial_const::ConstU8::<2>::new(), partial_const::ConstU8::<1>::new()));
-/// assert!(geq(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<1>::new()));
-/// assert!(geq(partial_const::ConstU8::<1>::new(), 1));
-/// assert!(!geq(partial_const::ConstU8::<1>::new(), 2));
-/// // assert!(geq(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<2>::new())); //<- Compile Error
-/// # }
-/// # #[cfg(feature = "u8")] test();
-/// ```
-pub trait Geq<Rhs> {}
+/// Type-level selection used by [crate::Unify].
+#[doc(hidden)]
+pub struct UnifySelect<const EQUAL: bool>;
+
+#[doc(hidden)]
+pub trait UnifyPick<A: crate::MayBeConstAT, B: crate::MayBeConst<A::Type>> {
+    type Output: crate::MayBeConst<A::Type>;
+    fn pick(a: A, b: B) -> Self::Output;
+}
+
+impl<A: crate::MayBeConstAT, B: crate::MayBeConst<A::Type>> UnifyPick<A, B> for UnifySelect<true> {
+    type Output = A;
+    #[inline(always)]
+    fn pick(a: A, _: B) -> A {
+        a
+    }
+}
+
+impl<A: crate::MayBeConstAT, B: crate::MayBeConst<A::Type>> UnifyPick<A, B> for UnifySelect<false> {
+    type Output = B;
+    #[inline(always)]
+    fn pick(_: A, b: B) -> B {
+        b
+    }
+}
 
 macro_rules! impl_incomplete {
     ($t:tt) => {
-        const fn lt(a: $t, b: $t) -> usize {
-            assert!(a < b);
-            0
-        }
-        impl<const VALUE1: $t, const VALUE2: $t> crate::Lt<Const<VALUE2>> for Const<VALUE1> where [(); lt(VALUE1, VALUE2)]: Sized {}
-        impl<const VALUE: $t> crate::Lt<$t> for Const<VALUE> {}
-        impl<const VALUE: $t> crate::Lt<Const<VALUE>> for $t {}
-        impl crate::Lt<$t> for $t {}
-
-        const fn gt(a: $t, b: $t) -> usize {
-            assert!(a > b);
-            0
-        }
-        impl<const VALUE1: $t, const VALUE2: $t> crate::Gt<Const<VALUE2>> for Const<VALUE1> where [(); gt(VALUE1, VALUE2)]: Sized {}
-        impl<const VALUE: $t> crate::Gt<$t> for Const<VALUE> {}
-        impl<const VALUE: $t> crate::Gt<Const<VALUE>> for $t {}
-        impl crate::Gt<$t> for $t {}
-
-        const fn leq(a: $t, b: $t) -> usize {
-            assert!(a <= b);
-            0
-        }
-        impl<const VALUE1: $t, const VALUE2: $t> crate::Leq<Const<VALUE2>> for Const<VALUE1> where [(); leq(VALUE1, VALUE2)]: Sized {}
-        impl<const VALUE: $t> crate::Leq<$t> for Const<VALUE> {}
-        impl<const VALUE: $t> crate::Leq<Const<VALUE>> for $t {}
-        impl crate::Leq<$t> for $t {}
-
-        const fn geq(a: $t, b: $t) -> usize {
-            assert!(a >= b);
-            0
-        }
-        impl<const VALUE1: $t, const VALUE2: $t> crate::Geq<Const<VALUE2>> for Const<VALUE1> where [(); geq(VALUE1, VALUE2)]: Sized {}
-        impl<const VALUE: $t> crate::Geq<$t> for Const<VALUE> {}
-        impl<const VALUE: $t> crate::Geq<Const<VALUE>> for $t {}
-        impl crate::Geq<$t> for $t {}
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Lt<Const<VALUE2>> for Const<VALUE1> where crate::Assert<{ VALUE1 < VALUE2 }>: crate::IsTrue {
+            impl_ordering_check!(Lt, Const<VALUE2>, const);
+        }
+
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Gt<Const<VALUE2>> for Const<VALUE1> where crate::Assert<{ VALUE1 > VALUE2 }>: crate::IsTrue {
+            impl_ordering_check!(Gt, Const<VALUE2>, const);
+        }
+
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Leq<Const<VALUE2>> for Const<VALUE1> where crate::Assert<{ VALUE1 <= VALUE2 }>: crate::IsTrue {
+            impl_ordering_check!(Leq, Const<VALUE2>, const);
+        }
+
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Geq<Const<VALUE2>> for Const<VALUE1> where crate::Assert<{ VALUE1 >= VALUE2 }>: crate::IsTrue {
+            impl_ordering_check!(Geq, Const<VALUE2>, const);
+        }
+
+        impl<const VALUE1: $t, const VALUE2: $t> crate::NotEquals<Const<VALUE2>> for Const<VALUE1> where crate::Assert<{ VALUE1 != VALUE2 }>: crate::IsTrue {
+            impl_ordering_check!(NotEquals::check_distinct, Const<VALUE2>, const);
+        }
+
+        impl<const VALUE: $t, const LO: $t, const HI: $t> crate::InRange<Const<LO>, Const<HI>> for Const<VALUE> where crate::Assert<{ (LO <= VALUE) & (VALUE <= HI) }>: crate::IsTrue {
+            impl_in_range_check!(Const<LO>, Const<HI>, const);
+        }
+
+        impl_incomplete_min_max!($t);
+
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Unify<Const<VALUE2>> for Const<VALUE1>
+            where crate::UnifySelect<{ VALUE1 == VALUE2 }>: crate::UnifyPick<Const<VALUE1>, $t> {
+            type Output = <crate::UnifySelect<{ VALUE1 == VALUE2 }> as crate::UnifyPick<Const<VALUE1>, $t>>::Output;
+            #[inline(always)]
+            fn unify(&self, _: &Const<VALUE2>) -> Option<Self::Output> {
+                if VALUE1 == VALUE2 {
+                    Some(<crate::UnifySelect<{ VALUE1 == VALUE2 }> as crate::UnifyPick<Const<VALUE1>, $t>>::pick(*self, VALUE2))
+                } else {
+                    None
+                }
+            }
+        }
 
         #[cfg(test)]
         mod test_incomplete {
             use super::*;
+            #[test]
+            fn test_unify() {
+                use crate::Unify;
+                #[allow(clippy::multiple_bound_locations)]
+                fn output_is_const<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() -> bool where A: Unify<B> {
+                    <<A as Unify<B>>::Output as crate::MayBeConstAT>::IS_CONST
+                }
+                assert!(output_is_const::<Const<{0 as $t}>, Const<{0 as $t}>>());
+                assert!(!output_is_const::<Const<{1 as $t}>, Const<{0 as $t}>>());
+
+                let unified: Option<Const<{0 as $t}>> = Const::<{0 as $t}>::new().unify(&Const::<{0 as $t}>::new());
+                assert_eq!(unified, Some(Const::<{0 as $t}>::new()));
+                let unified: Option<$t> = Const::<{1 as $t}>::new().unify(&Const::<{0 as $t}>::new());
+                assert_eq!(unified, None);
+            }
+
             #[test]
             fn test_lt() {
+                #[allow(clippy::multiple_bound_locations)]
                 fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::Lt<B> {}
                 test::<Const<{1 as $t}>, Const<{2 as $t}>>();
                 test::<Const<{1 as $t}>, $t>();
@@ -136,6 +98,7 @@ macro_rules! impl_incomplete {
 
             #[test]
             fn test_gt() {
+                #[allow(clippy::multiple_bound_locations)]
                 fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::Gt<B> {}
                 test::<Const<{2 as $t}>, Const<{1 as $t}>>();
                 test::<Const<{1 as $t}>, $t>();
@@ -147,6 +110,7 @@ macro_rules! impl_incomplete {
 
             #[test]
             fn test_leq() {
+                #[allow(clippy::multiple_bound_locations)]
                 fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::Leq<B> {}
                 test::<Const<{1 as $t}>, Const<{1 as $t}>>();
                 test::<Const<{1 as $t}>, Const<{2 as $t}>>();
@@ -158,6 +122,7 @@ macro_rules! impl_incomplete {
 
             #[test]
             fn test_geq() {
+                #[allow(clippy::multiple_bound_locations)]
                 fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::Geq<B> {}
                 test::<Const<{1 as $t}>, Const<{1 as $t}>>();
                 test::<Const<{2 as $t}>, Const<{1 as $t}>>();
@@ -166,6 +131,428 @@ macro_rules! impl_incomplete {
                 test::<$t, $t>();
                 // test::<Const<{1 as $t}>, Const<{2 as $t}>>(); // <-Compile Error!
             }
+
+            #[test]
+            fn test_not_equals() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::NotEquals<B> {}
+                test::<Const<{1 as $t}>, Const<{2 as $t}>>();
+                test::<Const<{2 as $t}>, Const<{1 as $t}>>();
+                test::<Const<{1 as $t}>, $t>();
+                test::<$t, Const<{1 as $t}>>();
+                test::<$t, $t>();
+                // test::<Const<{1 as $t}>, Const<{1 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_in_range() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, L: crate::MayBeConst<$t>, H: crate::MayBeConst<$t>>() where A: crate::InRange<L, H> {}
+                test::<Const<{1 as $t}>, Const<{1 as $t}>, Const<{2 as $t}>>();
+                test::<Const<{2 as $t}>, Const<{1 as $t}>, Const<{2 as $t}>>();
+                test::<Const<{1 as $t}>, Const<{1 as $t}>, $t>();
+                test::<$t, $t, $t>();
+                // test::<Const<{0 as $t}>, Const<{1 as $t}>, Const<{2 as $t}>>(); // <-Compile Error!
+                // test::<Const<{3 as $t}>, Const<{1 as $t}>, Const<{2 as $t}>>(); // <-Compile Error!
+            }
+        }
+    }
+}
+
+macro_rules! impl_incomplete_min_max {
+    ($t:tt) => {
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Min<Const<VALUE2>> for Const<VALUE1> where Const<{ MinMax::<VALUE1, VALUE2>::MIN }>: Sized {
+            type Output = Const<{ MinMax::<VALUE1, VALUE2>::MIN }>;
+            #[inline(always)]
+            fn minimum(self, _: Const<VALUE2>) -> Self::Output {
+                Const::new()
+            }
+        }
+
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Max<Const<VALUE2>> for Const<VALUE1> where Const<{ MinMax::<VALUE1, VALUE2>::MAX }>: Sized {
+            type Output = Const<{ MinMax::<VALUE1, VALUE2>::MAX }>;
+            #[inline(always)]
+            fn maximum(self, _: Const<VALUE2>) -> Self::Output {
+                Const::new()
+            }
+        }
+
+        impl<const VALUE: $t, const LO: $t, const HI: $t> crate::Clamp<Const<LO>, Const<HI>> for Const<VALUE>
+            where crate::Assert<{ LO <= HI }>: crate::IsTrue, Const<{ Clamped::<VALUE, LO, HI>::VALUE }>: Sized {
+            type Output = Const<{ Clamped::<VALUE, LO, HI>::VALUE }>;
+            #[inline(always)]
+            fn clamped(self, _: Const<LO>, _: Const<HI>) -> Self::Output {
+                Const::new()
+            }
+        }
+
+        #[cfg(test)]
+        mod test_incomplete_min_max {
+            use super::*;
+            #[test]
+            fn test_min_max_const() {
+                let _: Const<{1 as $t}> = crate::min(Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new());
+                let _: Const<{2 as $t}> = crate::max(Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new());
+                let _: Const<{2 as $t}> = crate::clamp(Const::<{3 as $t}>::new(), Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new());
+                let _: Const<{1 as $t}> = crate::clamp(Const::<{0 as $t}>::new(), Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new());
+                // let _ = crate::clamp(Const::<{3 as $t}>::new(), Const::<{2 as $t}>::new(), Const::<{1 as $t}>::new()); // <-Compile Error!
+            }
+        }
+    }
+}
+
+macro_rules! impl_incomplete_binary_op {
+    ($t:tt, $trait:ident, $method:ident, $op:tt) => {
+        impl<const VALUE1: $t, const VALUE2: $t> core::ops::$trait<Const<VALUE2>> for Const<VALUE1> where Const<{ VALUE1 $op VALUE2 }>: Sized {
+            type Output = Const<{ VALUE1 $op VALUE2 }>;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE2>) -> Self::Output {
+                Const::new()
+            }
+        }
+    }
+}
+
+macro_rules! impl_incomplete_checked_op {
+    ($t:tt, $trait:ident, $method:ident, $op:tt) => {
+        impl<const VALUE1: $t, const VALUE2: $t> crate::$trait<Const<VALUE2>> for Const<VALUE1> where Const<{ VALUE1 $op VALUE2 }>: Sized {
+            type Output = Option<Const<{ VALUE1 $op VALUE2 }>>;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE2>) -> Self::Output {
+                Some(Const::new())
+            }
+        }
+    };
+    (@exact $t:tt, $trait:ident, $method:ident, $op:tt) => {
+        impl<const VALUE1: $t, const VALUE2: $t> crate::$trait<Const<VALUE2>> for Const<VALUE1> where Const<{ VALUE1 $op VALUE2 }>: Sized {
+            type Output = Const<{ VALUE1 $op VALUE2 }>;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE2>) -> Self::Output {
+                Const::new()
+            }
+        }
+    };
+}
+
+macro_rules! impl_incomplete_wrapping_op {
+    ($t:tt, $trait:ident, $method:ident) => {
+        impl<const VALUE1: $t, const VALUE2: $t> crate::$trait<Const<VALUE2>> for Const<VALUE1> where Const<{ VALUE1.$method(VALUE2) }>: Sized {
+            type Output = Const<{ VALUE1.$method(VALUE2) }>;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE2>) -> Self::Output {
+                Const::new()
+            }
+        }
+    }
+}
+
+macro_rules! impl_incomplete_unary_op {
+    ($t:tt, $trait:ident, $method:ident, $op:tt) => {
+        impl<const VALUE: $t> core::ops::$trait for Const<VALUE> where Const<{ $op VALUE }>: Sized {
+            type Output = Const<{ $op VALUE }>;
+            #[inline(always)]
+            fn $method(self) -> Self::Output {
+                Const::new()
+            }
+        }
+    }
+}
+
+macro_rules! impl_incomplete_neg {
+    (unsigned, $t:tt) => {};
+    (signed, $t:tt) => {
+        impl_incomplete_unary_op!($t, Neg, neg, -);
+
+        #[cfg(test)]
+        mod test_incomplete_neg {
+            use super::*;
+            #[test]
+            fn test_neg_const() {
+                let _: Const<{-1 as $t}> = -Const::<{1 as $t}>::new();
+                // let _ = -Const::<{<$t>::MIN}>::new(); // <-Compile Error!
+            }
+        }
+    };
+}
+
+macro_rules! impl_incomplete_ops {
+    (char, $t:tt) => {};
+    ($kind:ident, $t:tt) => {
+        impl_incomplete_binary_op!($t, Add, add, +);
+        impl_incomplete_binary_op!($t, Sub, sub, -);
+        impl_incomplete_binary_op!($t, Mul, mul, *);
+        impl_incomplete_binary_op!($t, Div, div, /);
+        impl_incomplete_binary_op!($t, Rem, rem, %);
+        impl_incomplete_binary_op!($t, BitAnd, bitand, &);
+        impl_incomplete_binary_op!($t, BitOr, bitor, |);
+        impl_incomplete_binary_op!($t, BitXor, bitxor, ^);
+        impl_incomplete_binary_op!($t, Shl, shl, <<);
+        impl_incomplete_binary_op!($t, Shr, shr, >>);
+        impl_incomplete_checked_op!($t, CheckedAdd, checked_add, +);
+        impl_incomplete_checked_op!($t, CheckedSub, checked_sub, -);
+        impl_incomplete_checked_op!($t, CheckedMul, checked_mul, *);
+        impl_incomplete_checked_op!($t, CheckedDiv, checked_div, /);
+        impl_incomplete_checked_op!($t, CheckedRem, checked_rem, %);
+        impl_incomplete_checked_op!(@exact $t, SafeDiv, safe_div, /);
+        impl_incomplete_checked_op!(@exact $t, SafeRem, safe_rem, %);
+        impl_incomplete_wrapping_op!($t, WrappingAdd, wrapping_add);
+        impl_incomplete_wrapping_op!($t, WrappingSub, wrapping_sub);
+        impl_incomplete_wrapping_op!($t, WrappingMul, wrapping_mul);
+        impl_incomplete_wrapping_op!($t, SaturatingAdd, saturating_add);
+        impl_incomplete_wrapping_op!($t, SaturatingSub, saturating_sub);
+        impl_incomplete_wrapping_op!($t, SaturatingMul, saturating_mul);
+        impl_incomplete_unary_op!($t, Not, not, !);
+        impl_incomplete_neg!($kind, $t);
+
+        #[cfg(test)]
+        mod test_incomplete_ops {
+            use super::*;
+            #[test]
+            fn test_arithmetic_const() {
+                let _: Const<{9 as $t}> = Const::<{7 as $t}>::new() + Const::<{2 as $t}>::new();
+                let _: Const<{5 as $t}> = Const::<{7 as $t}>::new() - Const::<{2 as $t}>::new();
+                let _: Const<{14 as $t}> = Const::<{7 as $t}>::new() * Const::<{2 as $t}>::new();
+                let _: Const<{3 as $t}> = Const::<{7 as $t}>::new() / Const::<{2 as $t}>::new();
+                let _: Const<{1 as $t}> = Const::<{7 as $t}>::new() % Const::<{2 as $t}>::new();
+                // let _ = Const::<{<$t>::MAX}>::new() + Const::<{1 as $t}>::new(); // <-Compile Error!
+                // let _ = Const::<{1 as $t}>::new() / Const::<{0 as $t}>::new(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_bitwise_const() {
+                let _: Const<{2 as $t}> = Const::<{6 as $t}>::new() & Const::<{3 as $t}>::new();
+                let _: Const<{7 as $t}> = Const::<{6 as $t}>::new() | Const::<{3 as $t}>::new();
+                let _: Const<{5 as $t}> = Const::<{6 as $t}>::new() ^ Const::<{3 as $t}>::new();
+                let _: Const<{24 as $t}> = Const::<{6 as $t}>::new() << Const::<{2 as $t}>::new();
+                let _: Const<{1 as $t}> = Const::<{6 as $t}>::new() >> Const::<{2 as $t}>::new();
+                let _: Const<{!(0 as $t)}> = !Const::<{0 as $t}>::new();
+                // let _ = Const::<{1 as $t}>::new() << Const::<{<$t>::BITS as $t}>::new(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_checked_const() {
+                use crate::{CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, CheckedRem};
+                let _: Option<Const<{9 as $t}>> = CheckedAdd::checked_add(Const::<{7 as $t}>::new(), Const::<{2 as $t}>::new());
+                let _: Option<Const<{5 as $t}>> = CheckedSub::checked_sub(Const::<{7 as $t}>::new(), Const::<{2 as $t}>::new());
+                let _: Option<Const<{14 as $t}>> = CheckedMul::checked_mul(Const::<{7 as $t}>::new(), Const::<{2 as $t}>::new());
+                let _: Option<Const<{3 as $t}>> = CheckedDiv::checked_div(Const::<{7 as $t}>::new(), Const::<{2 as $t}>::new());
+                let _: Option<Const<{1 as $t}>> = CheckedRem::checked_rem(Const::<{7 as $t}>::new(), Const::<{2 as $t}>::new());
+                // CheckedAdd::checked_add(Const::<{<$t>::MAX}>::new(), Const::<{1 as $t}>::new()); // <-Compile Error!
+                // CheckedDiv::checked_div(Const::<{1 as $t}>::new(), Const::<{0 as $t}>::new()); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_may_be_const_int_const() {
+                use crate::MayBeConstInt;
+                let _: Option<Const<{3 as $t}>> = MayBeConstInt::checked_add(Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new());
+                let _: Const<{<$t>::MIN}> = MayBeConstInt::wrapping_add(Const::<{<$t>::MAX}>::new(), Const::<{1 as $t}>::new());
+                let _: Const<{<$t>::MAX}> = MayBeConstInt::saturating_mul(Const::<{<$t>::MAX}>::new(), Const::<{2 as $t}>::new());
+            }
+
+            #[test]
+            fn test_safe_div_const() {
+                use crate::{SafeDiv, SafeRem};
+                let _: Const<{12 as $t}> = SafeDiv::safe_div(Const::<{100 as $t}>::new(), Const::<{8 as $t}>::new());
+                let _: Const<{4 as $t}> = SafeRem::safe_rem(Const::<{100 as $t}>::new(), Const::<{8 as $t}>::new());
+                // SafeDiv::safe_div(Const::<{1 as $t}>::new(), Const::<{0 as $t}>::new()); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_wrapping_const() {
+                use crate::{WrappingAdd, WrappingSub, WrappingMul, SaturatingAdd, SaturatingSub, SaturatingMul};
+                let _: Const<{<$t>::MIN}> = WrappingAdd::wrapping_add(Const::<{<$t>::MAX}>::new(), Const::<{1 as $t}>::new());
+                let _: Const<{<$t>::MAX}> = WrappingSub::wrapping_sub(Const::<{<$t>::MIN}>::new(), Const::<{1 as $t}>::new());
+                let _: Const<{<$t>::MAX.wrapping_mul(2)}> = WrappingMul::wrapping_mul(Const::<{<$t>::MAX}>::new(), Const::<{2 as $t}>::new());
+                let _: Const<{<$t>::MAX}> = SaturatingAdd::saturating_add(Const::<{<$t>::MAX}>::new(), Const::<{1 as $t}>::new());
+                let _: Const<{<$t>::MIN}> = SaturatingSub::saturating_sub(Const::<{<$t>::MIN}>::new(), Const::<{1 as $t}>::new());
+                let _: Const<{<$t>::MAX}> = SaturatingMul::saturating_mul(Const::<{<$t>::MAX}>::new(), Const::<{2 as $t}>::new());
+            }
+
+            #[test]
+            fn test_arithmetic_output_is_const() {
+                fn output_is_const<A: core::ops::Add<B>, B>() -> bool where A::Output: crate::MayBeConstAT {
+                    <A::Output as crate::MayBeConstAT>::IS_CONST
+                }
+                assert!(output_is_const::<Const<{1 as $t}>, Const<{2 as $t}>>());
+            }
+        }
+    };
+}
+
+macro_rules! impl_incomplete_props {
+    (char, $t:tt) => {};
+    ($kind:ident, $t:tt) => {
+        impl<const VALUE: $t> crate::NonZero for Const<VALUE> where crate::Assert<{ VALUE != 0 }>: crate::IsTrue {
+            impl_property_check!(NonZero, const);
+        }
+
+        impl<const VALUE: $t> crate::PowerOfTwo for Const<VALUE> where crate::Assert<{ (VALUE > 0) & (VALUE & VALUE.wrapping_sub(1) == 0) }>: crate::IsTrue {
+            impl_property_check!(PowerOfTwo, const);
+        }
+
+        impl<const VALUE: $t> crate::Even for Const<VALUE> where crate::Assert<{ VALUE % 2 == 0 }>: crate::IsTrue {
+            impl_property_check!(Even, const);
+        }
+
+        impl<const VALUE: $t> crate::Odd for Const<VALUE> where crate::Assert<{ VALUE % 2 != 0 }>: crate::IsTrue {
+            impl_property_check!(Odd, const);
+        }
+
+        impl<const VALUE: $t, const M: $t> crate::MultipleOf<Const<M>> for Const<VALUE> where crate::Assert<{ Operands::<VALUE, M>::MULTIPLE_OF }>: crate::IsTrue {
+            impl_multiple_of_check!(Const<M>, const);
+        }
+
+        impl_incomplete_sign!($kind, $t);
+
+        #[cfg(test)]
+        mod test_incomplete_props {
+            use super::*;
+            #[test]
+            fn test_props() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn non_zero<A: crate::MayBeConst<$t>>() where A: crate::NonZero {}
+                #[allow(clippy::multiple_bound_locations)]
+                fn power_of_two<A: crate::MayBeConst<$t>>() where A: crate::PowerOfTwo {}
+                #[allow(clippy::multiple_bound_locations)]
+                fn even<A: crate::MayBeConst<$t>>() where A: crate::Even {}
+                #[allow(clippy::multiple_bound_locations)]
+                fn odd<A: crate::MayBeConst<$t>>() where A: crate::Odd {}
+                #[allow(clippy::multiple_bound_locations)]
+                fn multiple_of<A: crate::MayBeConst<$t>, M: crate::MayBeConst<$t>>() where A: crate::MultipleOf<M> {}
+                non_zero::<Const<{1 as $t}>>();
+                power_of_two::<Const<{64 as $t}>>();
+                even::<Const<{2 as $t}>>();
+                odd::<Const<{3 as $t}>>();
+                multiple_of::<Const<{12 as $t}>, Const<{4 as $t}>>();
+                multiple_of::<Const<{12 as $t}>, $t>();
+                // non_zero::<Const<{0 as $t}>>(); // <-Compile Error!
+                // power_of_two::<Const<{12 as $t}>>(); // <-Compile Error!
+                // even::<Const<{3 as $t}>>(); // <-Compile Error!
+                // odd::<Const<{2 as $t}>>(); // <-Compile Error!
+                // multiple_of::<Const<{10 as $t}>, Const<{4 as $t}>>(); // <-Compile Error!
+            }
+        }
+    };
+}
+
+macro_rules! impl_incomplete_sign {
+    (unsigned, $t:tt) => {};
+    (signed, $t:tt) => {
+        impl<const VALUE: $t> crate::Positive for Const<VALUE> where crate::Assert<{ VALUE > 0 }>: crate::IsTrue {
+            impl_property_check!(Positive::check_sign, const);
+        }
+
+        impl<const VALUE: $t> crate::NonNegative for Const<VALUE> where crate::Assert<{ VALUE >= 0 }>: crate::IsTrue {
+            impl_property_check!(NonNegative::check_sign, const);
+        }
+
+        impl<const VALUE: $t> crate::Negative for Const<VALUE> where crate::Assert<{ VALUE < 0 }>: crate::IsTrue {
+            impl_property_check!(Negative::check_sign, const);
+        }
+
+        #[cfg(test)]
+        mod test_incomplete_sign {
+            use super::*;
+            #[test]
+            fn test_sign() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn positive<A: crate::MayBeConst<$t>>() where A: crate::Positive {}
+                #[allow(clippy::multiple_bound_locations)]
+                fn non_negative<A: crate::MayBeConst<$t>>() where A: crate::NonNegative {}
+                #[allow(clippy::multiple_bound_locations)]
+                fn negative<A: crate::MayBeConst<$t>>() where A: crate::Negative {}
+                positive::<Const<{1 as $t}>>();
+                non_negative::<Const<{0 as $t}>>();
+                negative::<Const<{-1 as $t}>>();
+                // positive::<Const<{0 as $t}>>(); // <-Compile Error!
+                // non_negative::<Const<{-1 as $t}>>(); // <-Compile Error!
+                // negative::<Const<{0 as $t}>>(); // <-Compile Error!
+            }
+        }
+    };
+}
+
+macro_rules! impl_incomplete_unsigned {
+    ($from:tt, $to:tt) => {
+        impl<const VALUE: $from> crate::$from::Const<VALUE> {
+            /// Convert the non-negative constant into the constant of the matching unsigned type.
+            #[inline(always)]
+            pub fn to_unsigned(self) -> crate::$to::Const<{ VALUE as $to }> where Self: crate::NonNegative, crate::$to::Const<{ VALUE as $to }>: Sized {
+                crate::$to::Const::new()
+            }
+        }
+    }
+}
+
+macro_rules! impl_incomplete_widen {
+    ($from:tt, $to:tt) => {
+        impl<const VALUE: $from> crate::Widen<$to> for crate::$from::Const<VALUE> where crate::$to::Const<{ VALUE as $to }>: Sized {
+            type Output = crate::$to::Const<{ VALUE as $to }>;
+            #[inline(always)]
+            fn widen(self) -> Self::Output {
+                crate::$to::Const::new()
+            }
+        }
+    }
+}
+
+macro_rules! impl_incomplete_value_equals {
+    ($from:tt, $to:tt) => {
+        impl<const VALUE1: $from, const VALUE2: $to> crate::ValueEquals<crate::$to::Const<VALUE2>> for crate::$from::Const<VALUE1>
+            where crate::Assert<{ crate::AnyInt::eq(crate::$from::Const::<VALUE1>::ANY_INT, crate::$to::Const::<VALUE2>::ANY_INT) }>: crate::IsTrue {
+            type ConstSide = Self;
+            #[inline(always)]
+            fn get_value_const_side(&self, _: &crate::$to::Const<VALUE2>) -> Option<Self> {
+                Some(*self)
+            }
+        }
+    }
+}
+
+macro_rules! impl_incomplete_compare {
+    ($a:tt, $b:tt) => {
+        impl_incomplete_compare!(@ordering $a, $b, Lt, lt);
+        impl_incomplete_compare!(@ordering $a, $b, Gt, gt);
+        impl_incomplete_compare!(@ordering $a, $b, Leq, leq);
+        impl_incomplete_compare!(@ordering $a, $b, Geq, geq);
+    };
+    (@ordering $a:tt, $b:tt, $trait:ident, $cmp:ident) => {
+        impl<const VALUE1: $a, const VALUE2: $b> crate::$trait<crate::$b::Const<VALUE2>> for crate::$a::Const<VALUE1>
+            where crate::Assert<{ crate::AnyInt::$cmp(crate::$a::Const::<VALUE1>::ANY_INT, crate::$b::Const::<VALUE2>::ANY_INT) }>: crate::IsTrue {
+            impl_ordering_check!($trait, crate::$b::Const<VALUE2>, const);
+        }
+    };
+}
+
+macro_rules! impl_incomplete_cast {
+    ($from:tt, $to:tt) => {
+        impl<const VALUE: $from> crate::TryCastTo<$to> for crate::$from::Const<VALUE> where crate::$to::Const<{ <crate::$from::Const<VALUE> as crate::ConstCast<$to>>::TRY_CAST }>: Sized {
+            type Output = crate::$to::Const<{ <crate::$from::Const<VALUE> as crate::ConstCast<$to>>::TRY_CAST }>;
+            #[inline(always)]
+            fn try_cast_to(self) -> Self::Output {
+                crate::$to::Const::new()
+            }
+        }
+
+        impl<const VALUE: $from> crate::AsCast<$to> for crate::$from::Const<VALUE> where crate::$to::Const<{ VALUE as $to }>: Sized {
+            type Output = crate::$to::Const<{ VALUE as $to }>;
+            #[inline(always)]
+            fn as_cast(self) -> Self::Output {
+                crate::$to::Const::new()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "usize")]
+mod test_assert {
+    #[test]
+    fn test_generic_assert() {
+        fn bytes<const N: usize>() -> usize where crate::Assert<{ N >= 8 }>: crate::IsTrue {
+            N / 8
         }
+        assert_eq!(bytes::<16>(), 2);
+        // bytes::<4>(); // <-Compile Error!
     }
 }
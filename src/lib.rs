@@ -20,10 +20,28 @@
 //! # #[cfg(feature = "i32")] #[rustversion::not(since(1.51))] fn test(){}
 //! # test();
 //! ```
+//!
+//! # Constraints on constants
+//!
+//! Traits such as [Lt], [InRange] and [NonZero] put constraints on values which may be constants.
+//! Runtime values implement them at the type level and are checked by a method such as [Lt::check].
+//!
+//! With the `incomplete` feature on nightly, a constant implements a constraint trait only if it satisfies the constraint,
+//! so a bound such as `where A: Lt<B>` rejects a bad pair of constants by itself.
+//!
+//! Without it, every constant implements the constraint traits, and **the bound alone rejects nothing**.
+//! A violation is reported when the associated `ASSERT` constant of the trait is evaluated,
+//! which the checking methods such as [Lt::check] always do before returning.
+//! A function taking constrained values should therefore call the checking method, or evaluate `ASSERT`, for each of them.
 #![cfg_attr(feature = "incomplete", allow(incomplete_features))]
-#![cfg_attr(feature = "incomplete", feature(const_generics))]
-#![cfg_attr(feature = "incomplete", feature(const_evaluatable_checked))]
-#![cfg_attr(feature = "incomplete", feature(const_panic))]
+#![cfg_attr(feature = "incomplete", feature(generic_const_exprs))]
+
+include!("stable.rs");
+#[rustversion::since(1.51)]
+include!("min.rs");
+#[rustversion::all(since(1.51), nightly)]
+#[cfg(feature = "incomplete")]
+include!("incomplete.rs");
 
 #[cfg(feature = "bool")]
 /// module for [prim@bool]
@@ -34,30 +52,45 @@ pub mod bool;
 /// See [struct@bool::Const]
 pub type ConstBool<const VALUE: bool> = crate::bool::Const<VALUE>;
 
-include!("stable.rs");
+#[cfg(feature = "bool")]
+pub use crate::bool::IsTrue;
+
+#[cfg(feature = "bool")]
 #[rustversion::since(1.51)]
-include!("min.rs");
-#[rustversion::all(since(1.51), nightly)]
-#[cfg(feature = "incomplete")]
-include!("incomplete.rs");
+/// A compile-time assertion usable in where clauses, written as `Assert<{ expr }>: IsTrue`.
+/// See [bool::IsTrue].
+pub type Assert<const COND: bool> = crate::bool::Const<COND>;
 
 macro_rules! expand_impl {
-    ($c:tt, $t:tt, $($doc1:expr),*;$($doc2:expr),*;$($doc3:expr),*) => {
+    ($c:tt, $t:tt, $kind:ident, $($doc1:expr),*;$($doc2:expr),*;$($doc3:expr),*) => {
         $(#[doc=$doc1])*
         pub mod $t {
             impl_stable!($t);
+            impl_stable_props!($kind, $t);
             #[rustversion::since(1.51)]
             impl_min!($t, $($doc3),*);
+            #[rustversion::since(1.57)]
+            impl_min_ord!($t);
+            #[rustversion::since(1.57)]
+            impl_min_ops!($kind, $t);
+            #[rustversion::since(1.57)]
+            impl_min_props!($kind, $t);
             #[rustversion::all(since(1.51), nightly)]
             #[cfg(feature = "incomplete")]
             impl_incomplete!($t);
+            #[rustversion::all(since(1.51), nightly)]
+            #[cfg(feature = "incomplete")]
+            impl_incomplete_ops!($kind, $t);
+            #[rustversion::all(since(1.51), nightly)]
+            #[cfg(feature = "incomplete")]
+            impl_incomplete_props!($kind, $t);
         }
         #[rustversion::since(1.51)]
         impl_min_out!($c, $t, $($doc2),*);
     };
 }
 #[cfg(feature = "usize")]
-expand_impl!(ConstUsize, usize, "module for [prim@usize]"; "See [struct@usize::Const]";
+expand_impl!(ConstUsize, usize, unsigned, "module for [prim@usize]"; "See [struct@usize::Const]";
     "Constant [prim@usize] value",
     "",
     "# Example",
@@ -68,7 +101,7 @@ expand_impl!(ConstUsize, usize, "module for [prim@usize]"; "See [struct@usize::C
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "isize")]
-expand_impl!(ConstIsize, isize, "module for [prim@isize]"; "See [struct@isize::Const]";
+expand_impl!(ConstIsize, isize, signed, "module for [prim@isize]"; "See [struct@isize::Const]";
     "Constant [prim@isize] value",
     "",
     "# Example",
@@ -79,7 +112,7 @@ expand_impl!(ConstIsize, isize, "module for [prim@isize]"; "See [struct@isize::C
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "i8")]
-expand_impl!(ConstI8, i8, "module for [prim@i8]"; "See [struct@i8::Const]";
+expand_impl!(ConstI8, i8, signed, "module for [prim@i8]"; "See [struct@i8::Const]";
     "Constant [prim@i8] value",
     "",
     "# Example",
@@ -90,7 +123,7 @@ expand_impl!(ConstI8, i8, "module for [prim@i8]"; "See [struct@i8::Const]";
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "i16")]
-expand_impl!(ConstI16, i16, "module for [prim@i16]"; "See [struct@i16::Const]";
+expand_impl!(ConstI16, i16, signed, "module for [prim@i16]"; "See [struct@i16::Const]";
     "Constant [prim@i16] value",
     "",
     "# Example",
@@ -101,7 +134,7 @@ expand_impl!(ConstI16, i16, "module for [prim@i16]"; "See [struct@i16::Const]";
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "i32")]
-expand_impl!(ConstI32, i32, "module for [prim@i32]"; "See [struct@i32::Const]";
+expand_impl!(ConstI32, i32, signed, "module for [prim@i32]"; "See [struct@i32::Const]";
     "Constant [prim@i32] value",
     "",
     "# Example",
@@ -112,7 +145,7 @@ expand_impl!(ConstI32, i32, "module for [prim@i32]"; "See [struct@i32::Const]";
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "i64")]
-expand_impl!(ConstI64, i64, "module for [prim@i64]"; "See [struct@i64::Const]";
+expand_impl!(ConstI64, i64, signed, "module for [prim@i64]"; "See [struct@i64::Const]";
     "Constant [prim@i64] value",
     "",
     "# Example",
@@ -123,7 +156,7 @@ expand_impl!(ConstI64, i64, "module for [prim@i64]"; "See [struct@i64::Const]";
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "i128")]
-expand_impl!(ConstI128, i128, "module for [prim@i128]"; "See [struct@i128::Const]";
+expand_impl!(ConstI128, i128, signed, "module for [prim@i128]"; "See [struct@i128::Const]";
     "Constant [prim@i128] value",
     "",
     "# Example",
@@ -134,7 +167,7 @@ expand_impl!(ConstI128, i128, "module for [prim@i128]"; "See [struct@i128::Const
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "u8")]
-expand_impl!(ConstU8, u8, "module for [prim@u8]"; "See [struct@u8::Const]";
+expand_impl!(ConstU8, u8, unsigned, "module for [prim@u8]"; "See [struct@u8::Const]";
     "Constant [prim@u8] value",
     "",
     "# Example",
@@ -145,7 +178,7 @@ expand_impl!(ConstU8, u8, "module for [prim@u8]"; "See [struct@u8::Const]";
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "u16")]
-expand_impl!(ConstU16, u16, "module for [prim@u16]"; "See [struct@u16::Const]";
+expand_impl!(ConstU16, u16, unsigned, "module for [prim@u16]"; "See [struct@u16::Const]";
     "Constant [prim@u16] value",
     "",
     "# Example",
@@ -156,7 +189,7 @@ expand_impl!(ConstU16, u16, "module for [prim@u16]"; "See [struct@u16::Const]";
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "u32")]
-expand_impl!(ConstU32, u32, "module for [prim@u32]"; "See [struct@u32::Const]";
+expand_impl!(ConstU32, u32, unsigned, "module for [prim@u32]"; "See [struct@u32::Const]";
     "Constant [prim@u32] value",
     "",
     "# Example",
@@ -167,7 +200,7 @@ expand_impl!(ConstU32, u32, "module for [prim@u32]"; "See [struct@u32::Const]";
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "u64")]
-expand_impl!(ConstU64, u64, "module for [prim@u64]"; "See [struct@u64::Const]";
+expand_impl!(ConstU64, u64, unsigned, "module for [prim@u64]"; "See [struct@u64::Const]";
     "Constant [prim@u64] value",
     "",
     "# Example",
@@ -178,7 +211,7 @@ expand_impl!(ConstU64, u64, "module for [prim@u64]"; "See [struct@u64::Const]";
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "u128")]
-expand_impl!(ConstU128, u128, "module for [prim@u128]"; "See [struct@u128::Const]";
+expand_impl!(ConstU128, u128, unsigned, "module for [prim@u128]"; "See [struct@u128::Const]";
     "Constant [prim@u128] value",
     "",
     "# Example",
@@ -189,7 +222,7 @@ expand_impl!(ConstU128, u128, "module for [prim@u128]"; "See [struct@u128::Const
     "assert_eq!(const_value.value(), 42);",
     "```");
 #[cfg(feature = "char")]
-expand_impl!(ConstChar, char, "module for [prim@char]"; "See [struct@char::Const]";
+expand_impl!(ConstChar, char, char, "module for [prim@char]"; "See [struct@char::Const]";
     "Constant [prim@char] value",
     "",
     "# Example",
@@ -199,3 +232,378 @@ expand_impl!(ConstChar, char, "module for [prim@char]"; "See [struct@char::Const
     "assert_eq!(core::mem::size_of::<partial_const::char::Const<'A'>>(), 0);",
     "assert_eq!(const_value.value(), 'A');",
     "```");
+
+macro_rules! expand_widen {
+    ($($from:tt $from_feature:literal => $($to:tt $to_feature:literal),*;)*) => {
+        $($(
+            #[cfg(all(feature = $from_feature, feature = $to_feature))]
+            impl_stable_widen!($from, $to);
+            #[rustversion::since(1.51)]
+            #[cfg(all(feature = $from_feature, feature = $to_feature))]
+            impl_min_widen!($from, $to);
+            #[rustversion::all(since(1.51), nightly)]
+            #[cfg(all(feature = "incomplete", feature = $from_feature, feature = $to_feature))]
+            impl_incomplete_widen!($from, $to);
+        )*)*
+
+        #[cfg(test)]
+        mod test_widen {
+            #[test]
+            fn test_widen() {
+                $($(
+                    #[cfg(all(feature = $from_feature, feature = $to_feature))]
+                    {
+                        fn is_const<A: crate::Widen<$to>>() -> bool {
+                            <A::Output as crate::MayBeConstAT>::IS_CONST
+                        }
+                        assert_eq!(crate::Widen::<$to>::widen(65 as $from), 65 as $to);
+                        assert!(!is_const::<$from>());
+                    }
+                )*)*
+            }
+
+            #[rustversion::since(1.51)]
+            #[test]
+            fn test_widen_min() {
+                $($(
+                    #[cfg(all(feature = $from_feature, feature = $to_feature))]
+                    {
+                        let widened = crate::Widen::<$to>::widen(crate::$from::Const::<{65 as $from}>::new());
+                        assert_eq!(crate::MayBeConstAT::value(&widened), 65 as $to);
+                    }
+                )*)*
+            }
+
+            #[rustversion::all(since(1.51), nightly)]
+            #[cfg(feature = "incomplete")]
+            #[test]
+            fn test_widen_const() {
+                $($(
+                    #[cfg(all(feature = $from_feature, feature = $to_feature))]
+                    {
+                        let _: crate::$to::Const<{65 as $to}> = crate::Widen::<$to>::widen(crate::$from::Const::<{65 as $from}>::new());
+                    }
+                )*)*
+            }
+        }
+    };
+}
+expand_widen! {
+    u8 "u8" => u16 "u16", u32 "u32", u64 "u64", u128 "u128", usize "usize", i16 "i16", i32 "i32", i64 "i64", i128 "i128", isize "isize", char "char";
+    u16 "u16" => u32 "u32", u64 "u64", u128 "u128", usize "usize", i32 "i32", i64 "i64", i128 "i128";
+    u32 "u32" => u64 "u64", u128 "u128", i64 "i64", i128 "i128";
+    u64 "u64" => u128 "u128", i128 "i128";
+    i8 "i8" => i16 "i16", i32 "i32", i64 "i64", i128 "i128", isize "isize";
+    i16 "i16" => i32 "i32", i64 "i64", i128 "i128", isize "isize";
+    i32 "i32" => i64 "i64", i128 "i128";
+    i64 "i64" => i128 "i128";
+    char "char" => u32 "u32", u64 "u64", u128 "u128";
+}
+
+macro_rules! expand_unsigned {
+    ($($from:tt $from_feature:literal => $to:tt $to_feature:literal;)*) => {
+        $(
+            #[rustversion::since(1.57)]
+            #[cfg(all(feature = $from_feature, feature = $to_feature))]
+            impl_min_unsigned!($from, $to);
+            #[rustversion::all(since(1.57), nightly)]
+            #[cfg(all(feature = "incomplete", feature = $from_feature, feature = $to_feature))]
+            impl_incomplete_unsigned!($from, $to);
+        )*
+
+        #[cfg(test)]
+        mod test_unsigned {
+            #[rustversion::since(1.57)]
+            #[test]
+            fn test_unsigned() {
+                $(
+                    #[cfg(all(feature = $from_feature, feature = $to_feature))]
+                    {
+                        let value: crate::$to::Const<{1 as $to}> = crate::$from::Const::<{1 as $from}>::new().into();
+                        assert_eq!(value, crate::$to::Const::<{1 as $to}>::new());
+                        let _: crate::$to::Const<{<$from>::MAX as $to}> = crate::$from::Const::<{<$from>::MAX}>::new().into();
+                        // let _: crate::$to::Const<{<$from>::MAX as $to}> = crate::$from::Const::<{-1 as $from}>::new().into(); // <-Compile Error!
+                        // let _: crate::$to::Const<{2 as $to}> = crate::$from::Const::<{1 as $from}>::new().into(); // <-Compile Error!
+                    }
+                )*
+            }
+
+            #[rustversion::all(since(1.57), nightly)]
+            #[cfg(feature = "incomplete")]
+            #[test]
+            fn test_to_unsigned() {
+                $(
+                    #[cfg(all(feature = $from_feature, feature = $to_feature))]
+                    {
+                        let _: crate::$to::Const<{1 as $to}> = crate::$from::Const::<{1 as $from}>::new().to_unsigned();
+                        // crate::$from::Const::<{-1 as $from}>::new().to_unsigned(); // <-Compile Error!
+                    }
+                )*
+            }
+        }
+    };
+}
+expand_unsigned! {
+    isize "isize" => usize "usize";
+    i8 "i8" => u8 "u8";
+    i16 "i16" => u16 "u16";
+    i32 "i32" => u32 "u32";
+    i64 "i64" => u64 "u64";
+    i128 "i128" => u128 "u128";
+}
+
+macro_rules! expand_compare {
+    ($signed:tt, $unsigned:tt) => {
+        expand_compare!(@impl $signed $unsigned);
+
+        #[cfg(test)]
+        mod test_compare {
+            expand_compare!(@test $signed $unsigned);
+        }
+    };
+    (@impl [$($s:tt $s_feature:literal),*] $unsigned:tt) => {
+        $(expand_compare!(@pair $s $s_feature $unsigned);)*
+    };
+    (@pair $s:tt $s_feature:literal [$($u:tt $u_feature:literal),*]) => {
+        $(
+            #[rustversion::since(1.57)]
+            #[cfg(all(feature = $s_feature, feature = $u_feature))]
+            impl_min_compare!($s, $u);
+            #[rustversion::since(1.57)]
+            #[cfg(all(feature = $s_feature, feature = $u_feature))]
+            impl_min_compare!($u, $s);
+            #[rustversion::all(since(1.57), nightly)]
+            #[cfg(all(feature = "incomplete", feature = $s_feature, feature = $u_feature))]
+            impl_incomplete_compare!($s, $u);
+            #[rustversion::all(since(1.57), nightly)]
+            #[cfg(all(feature = "incomplete", feature = $s_feature, feature = $u_feature))]
+            impl_incomplete_compare!($u, $s);
+        )*
+    };
+    (@test [$($s:tt $s_feature:literal),*] $unsigned:tt) => {
+        $(expand_compare!(@test_signed $s $s_feature $unsigned);)*
+    };
+    (@test_signed $s:tt $s_feature:literal [$($u:tt $u_feature:literal),*]) => {
+        #[cfg(feature = $s_feature)]
+        mod $s {
+            #[rustversion::since(1.57)]
+            #[test]
+            fn test_compare() {
+                use core::cmp::Ordering;
+                $(
+                    #[cfg(feature = $u_feature)]
+                    {
+                        let minus_one = crate::$s::Const::<{-1 as $s}>::new();
+                        let one = crate::$s::Const::<{1 as $s}>::new();
+                        let max = crate::$u::Const::<{<$u>::MAX}>::new();
+                        assert_eq!(minus_one.partial_cmp(&max), Some(Ordering::Less));
+                        assert_eq!(max.partial_cmp(&minus_one), Some(Ordering::Greater));
+                        assert_eq!(one.partial_cmp(&crate::$u::Const::<{1 as $u}>::new()), Some(Ordering::Equal));
+                        assert!(one == crate::$u::Const::<{1 as $u}>::new());
+                        assert!(minus_one != max);
+
+                        assert!(minus_one < max);
+                        assert!(max > minus_one);
+                        assert!(one <= crate::$u::Const::<{1 as $u}>::new());
+                    }
+                )*
+            }
+
+            #[rustversion::since(1.57)]
+            #[test]
+            fn test_value_ord() {
+                use core::cmp::Ordering;
+                use crate::ValueOrd;
+                $(
+                    #[cfg(feature = $u_feature)]
+                    {
+                        let minus_one = crate::$s::Const::<{-1 as $s}>::new();
+                        let max = crate::$u::Const::<{<$u>::MAX}>::new();
+                        assert_eq!(minus_one.value_cmp(&max), Ordering::Less);
+                        assert_eq!(max.value_cmp(&minus_one), Ordering::Greater);
+                        assert_eq!(minus_one.value_cmp(&<$u>::MAX), Ordering::Less);
+                        assert_eq!(crate::$s::Const::<{1 as $s}>::new().value_cmp(&(1 as $u)), Ordering::Equal);
+                        assert_eq!((-1 as $s).value_cmp(&max), Ordering::Less);
+                        assert_eq!(<$s>::MIN.value_cmp(&crate::$u::Const::<{0 as $u}>::new()), Ordering::Less);
+                        assert_eq!(<$u>::MAX.value_cmp(&minus_one), Ordering::Greater);
+                        assert_eq!((0 as $u).value_cmp(&crate::$s::Const::<{<$s>::MIN}>::new()), Ordering::Greater);
+                        assert_eq!((-1 as $s).value_cmp(&<$u>::MAX), Ordering::Less);
+                        assert_eq!(<$u>::MAX.value_cmp(&(-1 as $s)), Ordering::Greater);
+                        assert_eq!((1 as $u).value_cmp(&(1 as $s)), Ordering::Equal);
+                    }
+                )*
+            }
+
+            #[rustversion::since(1.57)]
+            #[test]
+            fn test_ordering() {
+                use crate::{Gt, Geq, Leq, Lt};
+                #[allow(clippy::multiple_bound_locations)]
+                fn lt<A: crate::MayBeConstAT, B: crate::MayBeConstAT>() where A: Lt<B> {
+                    let () = <A as Lt<B>>::ASSERT;
+                }
+                $(
+                    #[cfg(feature = $u_feature)]
+                    {
+                        lt::<crate::$s::Const<{-1 as $s}>, crate::$u::Const<{0 as $u}>>();
+                        lt::<crate::$u::Const<{0 as $u}>, crate::$s::Const<{1 as $s}>>();
+                        // lt::<crate::$u::Const<{<$u>::MAX}>, crate::$s::Const<{-1 as $s}>>(); // <-Compile Error!
+
+                        assert!(Lt::check(&crate::$s::Const::<{-1 as $s}>::new(), &crate::$u::Const::<{<$u>::MAX}>::new()).is_ok());
+                        assert!(Gt::check(&crate::$u::Const::<{0 as $u}>::new(), &crate::$s::Const::<{<$s>::MIN}>::new()).is_ok());
+                        assert!(Leq::check(&crate::$s::Const::<{1 as $s}>::new(), &crate::$u::Const::<{1 as $u}>::new()).is_ok());
+                        assert!(Geq::check(&crate::$u::Const::<{1 as $u}>::new(), &crate::$s::Const::<{1 as $s}>::new()).is_ok());
+                        // Leq::check(&crate::$u::Const::<{<$u>::MAX}>::new(), &crate::$s::Const::<{-1 as $s}>::new()); // <-Compile Error!
+                    }
+                )*
+            }
+
+            #[rustversion::all(since(1.57), nightly)]
+            #[cfg(feature = "incomplete")]
+            #[test]
+            fn test_ordering_incomplete() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn lt<A: crate::MayBeConstAT, B: crate::MayBeConstAT>() where A: crate::Lt<B> {}
+                #[allow(clippy::multiple_bound_locations)]
+                fn geq<A: crate::MayBeConstAT, B: crate::MayBeConstAT>() where A: crate::Geq<B> {}
+                $(
+                    #[cfg(feature = $u_feature)]
+                    {
+                        lt::<crate::$s::Const<{-1 as $s}>, crate::$u::Const<{<$u>::MAX}>>();
+                        geq::<crate::$u::Const<{0 as $u}>, crate::$s::Const<{0 as $s}>>();
+                        // lt::<crate::$u::Const<{<$u>::MAX}>, crate::$s::Const<{-1 as $s}>>(); // <-Compile Error!
+                    }
+                )*
+            }
+        }
+    };
+}
+expand_compare!(
+    [isize "isize", i8 "i8", i16 "i16", i32 "i32", i64 "i64", i128 "i128"],
+    [usize "usize", u8 "u8", u16 "u16", u32 "u32", u64 "u64", u128 "u128"]
+);
+
+macro_rules! expand_cast {
+    ($($t:tt $feature:literal),*) => {
+        expand_cast!(@from [$($t $feature),*] $($t $feature),*);
+    };
+    (@from $targets:tt $($from:tt $from_feature:literal),*) => {
+        $(expand_cast!(@impl $from $from_feature $targets);)*
+
+        #[cfg(test)]
+        mod test_cast {
+            $(expand_cast!(@test $from $from_feature $targets);)*
+        }
+    };
+    (@impl $from:tt $from_feature:literal [$($to:tt $to_feature:literal),*]) => {
+        $(
+            #[cfg(all(feature = $from_feature, feature = $to_feature))]
+            impl_stable_cast!($from, $to);
+            #[rustversion::since(1.57)]
+            #[cfg(all(feature = $from_feature, feature = $to_feature))]
+            impl_min_cast!($from, $to);
+            #[rustversion::since(1.57)]
+            #[cfg(all(feature = $from_feature, feature = $to_feature))]
+            impl_min_value_equals!($from, $to);
+            #[rustversion::all(since(1.57), nightly)]
+            #[cfg(all(feature = "incomplete", feature = $from_feature, feature = $to_feature))]
+            impl_incomplete_cast!($from, $to);
+            #[rustversion::all(since(1.57), nightly)]
+            #[cfg(all(feature = "incomplete", feature = $from_feature, feature = $to_feature))]
+            impl_incomplete_value_equals!($from, $to);
+        )*
+    };
+    (@test $from:tt $from_feature:literal [$($to:tt $to_feature:literal),*]) => {
+        #[cfg(feature = $from_feature)]
+        mod $from {
+            #[test]
+            fn test_cast() {
+                use crate::{AsCast, TryCastTo};
+                $(
+                    #[cfg(feature = $to_feature)]
+                    {
+                        assert_eq!(TryCastTo::<$to>::try_cast_to(1 as $from), Ok(1 as $to));
+                        assert_eq!(TryCastTo::<$to>::try_cast_to(<$from>::MAX).ok(), <$to as core::convert::TryFrom<$from>>::try_from(<$from>::MAX).ok());
+                        assert_eq!(TryCastTo::<$to>::try_cast_to(<$from>::MIN).ok(), <$to as core::convert::TryFrom<$from>>::try_from(<$from>::MIN).ok());
+                        assert_eq!(AsCast::<$to>::as_cast(<$from>::MAX), <$from>::MAX as $to);
+                        assert_eq!(AsCast::<$to>::as_cast(<$from>::MIN), <$from>::MIN as $to);
+                    }
+                )*
+            }
+
+            #[rustversion::since(1.57)]
+            #[test]
+            fn test_cast_const() {
+                use crate::{AsCast, TryCastTo};
+                $(
+                    #[cfg(feature = $to_feature)]
+                    {
+                        assert_eq!(TryCastTo::<$to>::try_cast_to(crate::$from::Const::<{1 as $from}>::new()), 1 as $to);
+                        assert_eq!(crate::MayBeConstAT::value(&AsCast::<$to>::as_cast(crate::$from::Const::<{<$from>::MAX}>::new())), <$from>::MAX as $to);
+                        // TryCastTo::<$to>::try_cast_to(crate::$from::Const::<{<$from>::MAX}>::new()); // <-Compile Error if the value does not fit!
+                    }
+                )*
+            }
+
+            #[rustversion::since(1.57)]
+            #[test]
+            fn test_value_equals() {
+                use crate::ValueEquals;
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$from>, B: crate::MayBeConstAT>() where A: ValueEquals<B> {}
+                $(
+                    #[cfg(feature = $to_feature)]
+                    {
+                        test::<crate::$from::Const<{1 as $from}>, crate::$to::Const<{1 as $to}>>();
+                        test::<crate::$from::Const<{1 as $from}>, $to>();
+                        test::<$from, crate::$to::Const<{1 as $to}>>();
+                        test::<$from, $to>();
+                        // test::<crate::$from::Const<{1 as $from}>, crate::$to::Const<{2 as $to}>>(); // <-Compile Error!
+
+                        let one = crate::$from::Const::<{1 as $from}>::new();
+                        assert_eq!(one.get_value_const_side(&crate::$to::Const::<{1 as $to}>::new()), Some(one));
+                        assert_eq!(one.get_value_const_side(&(1 as $to)), Some(one));
+                        assert_eq!(one.get_value_const_side(&(2 as $to)), None);
+                        assert_eq!((1 as $from).get_value_const_side(&crate::$to::Const::<{1 as $to}>::new()), Some(crate::$to::Const::<{1 as $to}>::new()));
+                        assert_eq!((2 as $from).get_value_const_side(&crate::$to::Const::<{1 as $to}>::new()), None);
+                        assert_eq!((1 as $from).get_value_const_side(&(1 as $to)), Some(1 as $from));
+                        assert_eq!(<$from>::MAX.get_value_const_side(&(<$from>::MAX as $to)).is_some(), <$to as core::convert::TryFrom<$from>>::try_from(<$from>::MAX).is_ok());
+                        assert_eq!(<$from>::MIN.get_value_const_side(&(<$from>::MIN as $to)).is_some(), <$to as core::convert::TryFrom<$from>>::try_from(<$from>::MIN).is_ok());
+                        // ValueEquals::get_value_const_side(&one, &crate::$to::Const::<{2 as $to}>::new()); // <-Compile Error!
+                    }
+                )*
+            }
+
+            #[rustversion::all(since(1.57), nightly)]
+            #[cfg(feature = "incomplete")]
+            #[test]
+            fn test_value_equals_incomplete() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$from>, B: crate::MayBeConstAT>() where A: crate::ValueEquals<B> {}
+                $(
+                    #[cfg(feature = $to_feature)]
+                    {
+                        test::<crate::$from::Const<{1 as $from}>, crate::$to::Const<{1 as $to}>>();
+                        // test::<crate::$from::Const<{1 as $from}>, crate::$to::Const<{2 as $to}>>(); // <-Compile Error!
+                    }
+                )*
+            }
+
+            #[rustversion::all(since(1.57), nightly)]
+            #[cfg(feature = "incomplete")]
+            #[test]
+            fn test_cast_incomplete() {
+                use crate::{AsCast, TryCastTo};
+                $(
+                    #[cfg(feature = $to_feature)]
+                    {
+                        let _: crate::$to::Const<{1 as $to}> = TryCastTo::<$to>::try_cast_to(crate::$from::Const::<{1 as $from}>::new());
+                        let _: crate::$to::Const<{<$from>::MAX as $to}> = AsCast::<$to>::as_cast(crate::$from::Const::<{<$from>::MAX}>::new());
+                        let _: crate::$to::Const<{<$from>::MIN as $to}> = AsCast::<$to>::as_cast(crate::$from::Const::<{<$from>::MIN}>::new());
+                    }
+                )*
+            }
+        }
+    };
+}
+expand_cast!(usize "usize", isize "isize", i8 "i8", i16 "i16", i32 "i32", i64 "i64", i128 "i128", u8 "u8", u16 "u16", u32 "u32", u64 "u64", u128 "u128");
@@ -1,3 +1,472 @@
+/// A trait for checked addition of constant and non-constant values.
+/// For two constants the overflow is rejected at compile time and the sum is always `Some`, otherwise `None` is returned on failure.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u8")] fn test() {
+/// use partial_const::{CheckedAdd, ConstU8, MayBeConstAT};
+///
+/// assert_eq!(CheckedAdd::checked_add(ConstU8::<200>::new(), ConstU8::<50>::new()).map(|sum| sum.value()), Some(250));
+/// assert_eq!(CheckedAdd::checked_add(ConstU8::<200>::new(), 1u8), Some(201));
+/// assert_eq!(CheckedAdd::checked_add(200u8, ConstU8::<100>::new()), None);
+/// // CheckedAdd::checked_add(ConstU8::<200>::new(), ConstU8::<100>::new()); <- Compile Error
+/// # }
+/// # #[cfg(feature = "u8")] test();
+/// ```
+pub trait CheckedAdd<Rhs> {
+    type Output;
+    fn checked_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for checked subtraction of constant and non-constant values.
+/// For two constants the overflow is rejected at compile time and the difference is always `Some`, otherwise `None` is returned on failure.
+pub trait CheckedSub<Rhs> {
+    type Output;
+    fn checked_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for checked multiplication of constant and non-constant values.
+/// For two constants the overflow is rejected at compile time and the product is always `Some`, otherwise `None` is returned on failure.
+pub trait CheckedMul<Rhs> {
+    type Output;
+    fn checked_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for checked division of constant and non-constant values.
+/// For two constants the division by zero or overflow is rejected at compile time and the quotient is always `Some`, otherwise `None` is returned on failure.
+pub trait CheckedDiv<Rhs> {
+    type Output;
+    fn checked_div(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for checked remainder of constant and non-constant values.
+/// For two constants the division by zero or overflow is rejected at compile time and the remainder is always `Some`, otherwise `None` is returned on failure.
+pub trait CheckedRem<Rhs> {
+    type Output;
+    fn checked_rem(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for wrapping addition of constant and non-constant values.
+/// The result wraps around at the boundary of the type, and is computed at compile time for two constants.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u8")] fn test() {
+/// use partial_const::{WrappingAdd, ConstU8};
+///
+/// assert_eq!(WrappingAdd::wrapping_add(ConstU8::<200>::new(), ConstU8::<100>::new()), 44);
+/// assert_eq!(WrappingAdd::wrapping_add(ConstU8::<200>::new(), 100u8), 44);
+/// # }
+/// # #[cfg(feature = "u8")] test();
+/// ```
+pub trait WrappingAdd<Rhs> {
+    type Output;
+    fn wrapping_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for wrapping subtraction of constant and non-constant values.
+/// The result wraps around at the boundary of the type, and is computed at compile time for two constants.
+pub trait WrappingSub<Rhs> {
+    type Output;
+    fn wrapping_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for wrapping multiplication of constant and non-constant values.
+/// The result wraps around at the boundary of the type, and is computed at compile time for two constants.
+pub trait WrappingMul<Rhs> {
+    type Output;
+    fn wrapping_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for saturating addition of constant and non-constant values.
+/// The result saturates at the boundary of the type, and is computed at compile time for two constants.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u8")] fn test() {
+/// use partial_const::{SaturatingAdd, ConstU8};
+///
+/// assert_eq!(SaturatingAdd::saturating_add(ConstU8::<200>::new(), ConstU8::<100>::new()), 255);
+/// assert_eq!(SaturatingAdd::saturating_add(ConstU8::<200>::new(), 100u8), 255);
+/// # }
+/// # #[cfg(feature = "u8")] test();
+/// ```
+pub trait SaturatingAdd<Rhs> {
+    type Output;
+    fn saturating_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for saturating subtraction of constant and non-constant values.
+/// The result saturates at the boundary of the type, and is computed at compile time for two constants.
+pub trait SaturatingSub<Rhs> {
+    type Output;
+    fn saturating_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for saturating multiplication of constant and non-constant values.
+/// The result saturates at the boundary of the type, and is computed at compile time for two constants.
+pub trait SaturatingMul<Rhs> {
+    type Output;
+    fn saturating_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for division of constant and non-constant values without a panic path.
+/// A constant divisor is rejected at compile time if it is zero, and the quotient is returned directly.
+/// Otherwise `None` is returned on failure.
+///
+/// Division of an unsigned value by a constant power of two is a shift.
+/// For signed values, a constant divisor of `-1` is also rejected, since the division of `MIN` by it overflows.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u64")] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{ConstU64, SafeDiv, SafeRem};
+///
+/// assert_eq!(SafeDiv::safe_div(100u64, ConstU64::<8>::new()), 12);
+/// assert_eq!(SafeRem::safe_rem(100u64, ConstU64::<8>::new()), 4);
+/// assert_eq!(SafeDiv::safe_div(100u64, 8u64), Some(12));
+/// assert_eq!(SafeDiv::safe_div(100u64, 0u64), None);
+/// // SafeDiv::safe_div(100u64, ConstU64::<0>::new()); <- Compile Error
+/// # }
+/// # #[cfg(not(feature = "u64"))] fn test(){}
+/// # #[cfg(feature = "u64")] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait SafeDiv<Rhs> {
+    type Output;
+    fn safe_div(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for remainder of constant and non-constant values without a panic path.
+/// A constant divisor is rejected at compile time if it is zero, and the remainder is returned directly.
+/// Otherwise `None` is returned on failure.
+///
+/// Remainder of an unsigned value by a constant power of two is a mask.
+/// For signed values, a constant divisor of `-1` is also rejected, since the remainder of `MIN` by it overflows.
+pub trait SafeRem<Rhs> {
+    type Output;
+    fn safe_rem(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for handling constant and non-constant integers in a common way.
+/// It is implemented by every integer primitive and constant.
+///
+/// The properties of the type are associated constants.
+/// The binary operations are forwarded to the operator traits such as [CheckedAdd],
+/// so an operation on two constants keeps a constant result when the `incomplete` feature is enabled.
+/// Generic code which only needs the value can compute it with [PrimInt].
+///
+/// # Example
+/// ```
+/// # #[cfg(all(feature = "u8", feature = "i64"))] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{ConstI64, ConstU8, MayBeConstAT, MayBeConstInt, PrimInt};
+///
+/// fn sum<A: MayBeConstInt, B: MayBeConstInt<Int=A::Int>>(a: A, b: B) -> Option<A::Int> {
+///     PrimInt::checked_add(a.value(), b.value())
+/// }
+///
+/// assert_eq!(sum(ConstU8::<200>::new(), 50u8), Some(250));
+/// assert_eq!(sum(200u8, ConstU8::<100>::new()), None);
+/// assert_eq!(sum(ConstI64::<-1>::new(), ConstI64::<1>::new()), Some(0));
+/// assert_eq!(ConstU8::<200>::new().checked_add(ConstU8::<50>::new()).map(|sum| sum.value()), Some(250));
+/// assert_eq!(ConstU8::<200>::new().checked_add(100u8), None);
+/// assert_eq!(<ConstU8<0> as MayBeConstInt>::BITS, 8);
+/// assert!(<i64 as MayBeConstInt>::SIGNED);
+/// # }
+/// # #[cfg(not(all(feature = "u8", feature = "i64")))] fn test(){}
+/// # #[cfg(all(feature = "u8", feature = "i64"))] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait MayBeConstInt: MayBeConstAT<Type=<Self as MayBeConstInt>::Int> {
+    /// The primitive integer type, which is the same as [MayBeConstAT::Type].
+    type Int: PrimInt;
+    /// The size of the type in bits.
+    const BITS: u32 = <Self::Int as PrimInt>::BITS;
+    /// The smallest value of the type.
+    const MIN: Self::Int = <Self::Int as PrimInt>::MIN;
+    /// The largest value of the type.
+    const MAX: Self::Int = <Self::Int as PrimInt>::MAX;
+    /// Whether the type is signed.
+    const SIGNED: bool = <Self::Int as PrimInt>::SIGNED;
+
+    #[inline(always)]
+    fn checked_add<Rhs: MayBeConst<Self::Int>>(self, rhs: Rhs) -> <Self as CheckedAdd<Rhs>>::Output where Self: CheckedAdd<Rhs> {
+        CheckedAdd::checked_add(self, rhs)
+    }
+    #[inline(always)]
+    fn checked_sub<Rhs: MayBeConst<Self::Int>>(self, rhs: Rhs) -> <Self as CheckedSub<Rhs>>::Output where Self: CheckedSub<Rhs> {
+        CheckedSub::checked_sub(self, rhs)
+    }
+    #[inline(always)]
+    fn checked_mul<Rhs: MayBeConst<Self::Int>>(self, rhs: Rhs) -> <Self as CheckedMul<Rhs>>::Output where Self: CheckedMul<Rhs> {
+        CheckedMul::checked_mul(self, rhs)
+    }
+    #[inline(always)]
+    fn checked_div<Rhs: MayBeConst<Self::Int>>(self, rhs: Rhs) -> <Self as CheckedDiv<Rhs>>::Output where Self: CheckedDiv<Rhs> {
+        CheckedDiv::checked_div(self, rhs)
+    }
+    #[inline(always)]
+    fn checked_rem<Rhs: MayBeConst<Self::Int>>(self, rhs: Rhs) -> <Self as CheckedRem<Rhs>>::Output where Self: CheckedRem<Rhs> {
+        CheckedRem::checked_rem(self, rhs)
+    }
+    #[inline(always)]
+    fn wrapping_add<Rhs: MayBeConst<Self::Int>>(self, rhs: Rhs) -> <Self as WrappingAdd<Rhs>>::Output where Self: WrappingAdd<Rhs> {
+        WrappingAdd::wrapping_add(self, rhs)
+    }
+    #[inline(always)]
+    fn wrapping_sub<Rhs: MayBeConst<Self::Int>>(self, rhs: Rhs) -> <Self as WrappingSub<Rhs>>::Output where Self: WrappingSub<Rhs> {
+        WrappingSub::wrapping_sub(self, rhs)
+    }
+    #[inline(always)]
+    fn wrapping_mul<Rhs: MayBeConst<Self::Int>>(self, rhs: Rhs) -> <Self as WrappingMul<Rhs>>::Output where Self: WrappingMul<Rhs> {
+        WrappingMul::wrapping_mul(self, rhs)
+    }
+    #[inline(always)]
+    fn saturating_add<Rhs: MayBeConst<Self::Int>>(self, rhs: Rhs) -> <Self as SaturatingAdd<Rhs>>::Output where Self: SaturatingAdd<Rhs> {
+        SaturatingAdd::saturating_add(self, rhs)
+    }
+    #[inline(always)]
+    fn saturating_sub<Rhs: MayBeConst<Self::Int>>(self, rhs: Rhs) -> <Self as SaturatingSub<Rhs>>::Output where Self: SaturatingSub<Rhs> {
+        SaturatingSub::saturating_sub(self, rhs)
+    }
+    #[inline(always)]
+    fn saturating_mul<Rhs: MayBeConst<Self::Int>>(self, rhs: Rhs) -> <Self as SaturatingMul<Rhs>>::Output where Self: SaturatingMul<Rhs> {
+        SaturatingMul::saturating_mul(self, rhs)
+    }
+    #[inline(always)]
+    fn count_ones(&self) -> u32 {
+        PrimInt::count_ones(self.value())
+    }
+    #[inline(always)]
+    fn count_zeros(&self) -> u32 {
+        PrimInt::count_zeros(self.value())
+    }
+    #[inline(always)]
+    fn leading_zeros(&self) -> u32 {
+        PrimInt::leading_zeros(self.value())
+    }
+    #[inline(always)]
+    fn trailing_zeros(&self) -> u32 {
+        PrimInt::trailing_zeros(self.value())
+    }
+}
+
+/// A trait for the minimum of constant and non-constant values.
+/// The minimum of two constants is computed at compile time, and it is a constant with the `incomplete` feature.
+///
+/// The method is not named `min` to avoid the ambiguity with [Ord::min].
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "usize")] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{max, min, ConstUsize};
+///
+/// assert_eq!(min(ConstUsize::<16>::new(), ConstUsize::<64>::new()), 16);
+/// assert_eq!(min(ConstUsize::<16>::new(), 8usize), 8);
+/// assert_eq!(max(32usize, ConstUsize::<64>::new()), 64);
+/// # }
+/// # #[cfg(feature = "usize")] #[rustversion::not(since(1.57))] fn test(){}
+/// # #[cfg(feature = "usize")] test();
+/// ```
+pub trait Min<Rhs> {
+    type Output;
+    fn minimum(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for the maximum of constant and non-constant values.
+/// The maximum of two constants is computed at compile time, and it is a constant with the `incomplete` feature.
+///
+/// The method is not named `max` to avoid the ambiguity with [Ord::max].
+pub trait Max<Rhs> {
+    type Output;
+    fn maximum(self, rhs: Rhs) -> Self::Output;
+}
+
+/// A trait for restricting constant and non-constant values to the range `lo..=hi`.
+/// If all of them are constants, the result is computed at compile time, and it is a constant with the `incomplete` feature.
+///
+/// If both bounds are constants, `lo <= hi` is checked at compile time when [Clamp::ASSERT] is evaluated.
+/// Otherwise [Clamp::clamped] panics if `lo > hi`, like [Ord::clamp].
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u8")] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{clamp, ConstU8};
+///
+/// assert_eq!(clamp(ConstU8::<200>::new(), ConstU8::<1>::new(), ConstU8::<100>::new()), 100);
+/// assert_eq!(clamp(0u8, ConstU8::<1>::new(), ConstU8::<100>::new()), 1);
+/// assert_eq!(clamp(50u8, 1u8, ConstU8::<100>::new()), 50);
+/// // clamp(0u8, ConstU8::<100>::new(), ConstU8::<1>::new()); <- Compile Error
+/// # }
+/// # #[cfg(feature = "u8")] #[rustversion::not(since(1.57))] fn test(){}
+/// # #[cfg(feature = "u8")] test();
+/// ```
+pub trait Clamp<Lo, Hi> {
+    /// Evaluating this fails to compile if both bounds are constants and `lo > hi`.
+    const ASSERT: () = ();
+    type Output;
+    fn clamped(self, lo: Lo, hi: Hi) -> Self::Output;
+}
+
+/// Return the minimum of `a` and `b`. See [Min].
+#[inline(always)]
+pub fn min<A: Min<B>, B>(a: A, b: B) -> A::Output {
+    a.minimum(b)
+}
+
+/// Return the maximum of `a` and `b`. See [Max].
+#[inline(always)]
+pub fn max<A: Max<B>, B>(a: A, b: B) -> A::Output {
+    a.maximum(b)
+}
+
+/// Restrict `x` to the range `lo..=hi`. See [Clamp].
+#[inline(always)]
+pub fn clamp<X: Clamp<Lo, Hi>, Lo, Hi>(x: X, lo: Lo, hi: Hi) -> X::Output {
+    x.clamped(lo, hi)
+}
+
+/// A trait for putting equality constraints on integers of possibly different types.
+/// Unlike [crate::Equals], the values are compared by their mathematical values, so `ConstU8<4>` equals `ConstUsize<4>`.
+///
+/// With the `incomplete` feature, two constants implement it only if their values are equal.
+/// Without it, `ConstU8<4>: ValueEquals<ConstUsize<5>>` holds as well, together with its [ValueEquals::ConstSide],
+/// and different values are rejected only when [ValueEquals::get_value_const_side] or [ValueEquals::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+///
+/// # Example
+/// ```
+/// # #[cfg(all(feature = "u8", feature = "usize"))] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{ConstU8, ConstUsize, ValueEquals};
+///
+/// assert_eq!(ConstU8::<4>::new().get_value_const_side(&ConstUsize::<4>::new()), Some(ConstU8::<4>::new()));
+/// assert_eq!(ConstU8::<4>::new().get_value_const_side(&4usize), Some(ConstU8::<4>::new()));
+/// assert_eq!(4u8.get_value_const_side(&ConstUsize::<4>::new()), Some(ConstUsize::<4>::new()));
+/// assert_eq!(4u8.get_value_const_side(&5usize), None);
+/// // ConstU8::<4>::new().get_value_const_side(&ConstUsize::<5>::new()); <- Compile Error
+/// # }
+/// # #[cfg(not(all(feature = "u8", feature = "usize")))] fn test(){}
+/// # #[cfg(all(feature = "u8", feature = "usize"))] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait ValueEquals<Rhs: crate::MayBeConstAT>: crate::MayBeConstAT {
+    /// The const side of two types.
+    type ConstSide: crate::MayBeConstAT;
+    /// Evaluating this fails to compile if both sides are constants with different values.
+    const ASSERT: () = ();
+    /// Return the const side value of two values if two values are mathematically equal.
+    fn get_value_const_side(&self, rhs: &Rhs) -> Option<Self::ConstSide>;
+}
+
+/// A trait for comparing a signed and an unsigned integer by their mathematical values.
+/// Unlike an `as` cast, the comparison never wraps around, so `-1i8` is less than `255u8`.
+/// The ordering of two constants is computed at compile time.
+///
+/// Two constants of such types can also be compared with [PartialOrd] and the ordering constraints such as [crate::Lt].
+/// Values which are not both constants are compared only through this trait,
+/// so that comparing a constant with an unsuffixed literal still infers the type of the literal.
+///
+/// # Example
+/// ```
+/// # #[cfg(all(feature = "i8", feature = "u8"))] #[rustversion::since(1.57)] fn test() {
+/// use core::cmp::Ordering;
+/// use partial_const::{ConstI8, ConstU8, ValueOrd};
+///
+/// assert_eq!(ConstI8::<-1>::new().value_cmp(&ConstU8::<255>::new()), Ordering::Less);
+/// assert_eq!(ConstI8::<-1>::new().value_cmp(&255u8), Ordering::Less);
+/// assert_eq!(200u8.value_cmp(&ConstI8::<-56>::new()), Ordering::Greater);
+/// assert_eq!(1i8.value_cmp(&1u8), Ordering::Equal);
+/// assert!(ConstI8::<-1>::new() < ConstU8::<255>::new());
+/// # }
+/// # #[cfg(not(all(feature = "i8", feature = "u8")))] fn test(){}
+/// # #[cfg(all(feature = "i8", feature = "u8"))] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait ValueOrd<Rhs: crate::MayBeConstAT>: crate::MayBeConstAT {
+    /// Compare the mathematical values of two integers.
+    fn value_cmp(&self, rhs: &Rhs) -> core::cmp::Ordering;
+}
+
+/// An integer of any primitive integer type, which is compared by its mathematical value.
+/// `bits` is the value sign-extended to 128 bits.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct AnyInt {
+    negative: bool,
+    bits: u128,
+}
+
+impl AnyInt {
+    #[inline(always)]
+    pub const fn new(negative: bool, bits: u128) -> Self {
+        AnyInt { negative, bits }
+    }
+
+    #[inline(always)]
+    pub const fn cmp(self, rhs: AnyInt) -> core::cmp::Ordering {
+        if self.negative != rhs.negative {
+            if self.negative { core::cmp::Ordering::Less } else { core::cmp::Ordering::Greater }
+        } else if self.bits < rhs.bits {
+            core::cmp::Ordering::Less
+        } else if self.bits == rhs.bits {
+            core::cmp::Ordering::Equal
+        } else {
+            core::cmp::Ordering::Greater
+        }
+    }
+
+    #[inline(always)]
+    pub const fn eq(self, rhs: AnyInt) -> bool {
+        self.negative == rhs.negative && self.bits == rhs.bits
+    }
+
+    #[inline(always)]
+    pub const fn lt(self, rhs: AnyInt) -> bool {
+        matches!(self.cmp(rhs), core::cmp::Ordering::Less)
+    }
+
+    #[inline(always)]
+    pub const fn gt(self, rhs: AnyInt) -> bool {
+        matches!(self.cmp(rhs), core::cmp::Ordering::Greater)
+    }
+
+    #[inline(always)]
+    pub const fn leq(self, rhs: AnyInt) -> bool {
+        !self.gt(rhs)
+    }
+
+    #[inline(always)]
+    pub const fn geq(self, rhs: AnyInt) -> bool {
+        !self.lt(rhs)
+    }
+}
+
+macro_rules! any_int {
+    ($value:expr) => {
+        crate::AnyInt::new($value < 0, $value as u128)
+    };
+}
+
+/// Conversions of constants to another type, evaluated at compile time.
+#[doc(hidden)]
+pub trait ConstCast<T> {
+    /// The converted value.
+    /// Evaluating it fails to compile if the value does not fit in `T`.
+    const TRY_CAST: T;
+}
+
+macro_rules! impl_min_int {
+    (char) => {};
+    ($t:tt) => {
+        impl crate::MayBeConstInt for $t {
+            type Int = $t;
+        }
+        impl<const VALUE: $t> crate::MayBeConstInt for Const<VALUE> {
+            type Int = $t;
+        }
+    };
+}
+
 macro_rules! impl_min {
     ($t:tt, $($doc:expr),*) => {
         $(#[doc = $doc])*
@@ -9,6 +478,33 @@ macro_rules! impl_min {
             pub fn new() -> Self {
                 Default::default()
             }
+
+            /// Return the constant if `value` equals `VALUE`.
+            #[inline(always)]
+            pub fn try_from_value(value: $t) -> Result<Self, crate::ValueMismatch<$t>> {
+                if value == VALUE {
+                    Ok(Self::new())
+                } else {
+                    Err(crate::ValueMismatch::new(VALUE, value))
+                }
+            }
+
+            /// Return the constant, panicking if `value` does not equal `VALUE`.
+            #[inline(always)]
+            pub fn assert_from(value: $t) -> Self {
+                match Self::try_from_value(value) {
+                    Ok(value) => value,
+                    Err(error) => panic!("{}", error),
+                }
+            }
+        }
+
+        impl<const VALUE: $t> core::convert::TryFrom<$t> for Const<VALUE> {
+            type Error = crate::ValueMismatch<$t>;
+            #[inline(always)]
+            fn try_from(value: $t) -> Result<Self, Self::Error> {
+                Self::try_from_value(value)
+            }
         }
 
         impl<const VALUE: $t> core::fmt::Display for Const<VALUE> {
@@ -56,6 +552,91 @@ macro_rules! impl_min {
             }
         }
 
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Unify<Const<VALUE2>> for Const<VALUE1> {
+            type Output = $t;
+            #[inline(always)]
+            fn unify(&self, _: &Const<VALUE2>) -> Option<Self::Output> {
+                if VALUE1 == VALUE2 {
+                    Some(VALUE1)
+                } else {
+                    None
+                }
+            }
+        }
+        impl<const VALUE: $t> crate::Unify<$t> for Const<VALUE> {
+            type Output = $t;
+            #[inline(always)]
+            fn unify(&self, rhs: &$t) -> Option<Self::Output> {
+                if VALUE == *rhs {
+                    Some(VALUE)
+                } else {
+                    None
+                }
+            }
+        }
+        impl<const VALUE: $t> crate::Unify<Const<VALUE>> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn unify(&self, _: &Const<VALUE>) -> Option<Self::Output> {
+                if *self == VALUE {
+                    Some(VALUE)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<const VALUE: $t> crate::Lt<$t> for Const<VALUE> {
+            impl_ordering_check!(Lt, $t, <);
+        }
+        impl<const VALUE: $t> crate::Lt<Const<VALUE>> for $t {
+            impl_ordering_check!(Lt, Const<VALUE>, <);
+        }
+        impl<const VALUE: $t> crate::Gt<$t> for Const<VALUE> {
+            impl_ordering_check!(Gt, $t, >);
+        }
+        impl<const VALUE: $t> crate::Gt<Const<VALUE>> for $t {
+            impl_ordering_check!(Gt, Const<VALUE>, >);
+        }
+        impl<const VALUE: $t> crate::Leq<$t> for Const<VALUE> {
+            impl_ordering_check!(Leq, $t, <=);
+        }
+        impl<const VALUE: $t> crate::Leq<Const<VALUE>> for $t {
+            impl_ordering_check!(Leq, Const<VALUE>, <=);
+        }
+        impl<const VALUE: $t> crate::Geq<$t> for Const<VALUE> {
+            impl_ordering_check!(Geq, $t, >=);
+        }
+        impl<const VALUE: $t> crate::Geq<Const<VALUE>> for $t {
+            impl_ordering_check!(Geq, Const<VALUE>, >=);
+        }
+        impl<const VALUE: $t> crate::NotEquals<$t> for Const<VALUE> {
+            impl_ordering_check!(NotEquals::check_distinct, $t, !=);
+        }
+        impl<const VALUE: $t> crate::NotEquals<Const<VALUE>> for $t {
+            impl_ordering_check!(NotEquals::check_distinct, Const<VALUE>, !=);
+        }
+
+        impl<const LO: $t> crate::InRange<Const<LO>, $t> for $t {
+            impl_in_range_check!(Const<LO>, $t);
+        }
+        impl<const HI: $t> crate::InRange<$t, Const<HI>> for $t {
+            impl_in_range_check!($t, Const<HI>);
+        }
+        impl<const LO: $t, const HI: $t> crate::InRange<Const<LO>, Const<HI>> for $t {
+            impl_in_range_check!(Const<LO>, Const<HI>);
+        }
+        impl<const VALUE: $t> crate::InRange<$t, $t> for Const<VALUE> {
+            impl_in_range_check!($t, $t);
+        }
+        impl<const VALUE: $t, const LO: $t> crate::InRange<Const<LO>, $t> for Const<VALUE> {
+            impl_in_range_check!(Const<LO>, $t);
+        }
+        impl<const VALUE: $t, const HI: $t> crate::InRange<$t, Const<HI>> for Const<VALUE> {
+            impl_in_range_check!($t, Const<HI>);
+        }
+
         impl<const VALUE1: $t, const VALUE2: $t> core::cmp::PartialEq<Const<VALUE2>> for Const<VALUE1> {
             fn eq(&self, _: &Const<VALUE2>) -> bool { VALUE1 == VALUE2 }
         }
@@ -76,11 +657,24 @@ macro_rules! impl_min {
             fn partial_cmp(&self, _: &Const<VALUE>) -> Option<core::cmp::Ordering> { self.partial_cmp(&VALUE) }
         }
 
+        impl<const VALUE: $t> core::cmp::Eq for Const<VALUE> {}
+
+        impl_min_int!($t);
+
+        impl<const VALUE: $t> core::cmp::Ord for Const<VALUE> {
+            fn cmp(&self, _: &Const<VALUE>) -> core::cmp::Ordering { core::cmp::Ordering::Equal }
+        }
+
+        impl<const VALUE: $t> core::hash::Hash for Const<VALUE> {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) { VALUE.hash(state) }
+        }
+
         #[cfg(test)]
         mod test_min {
             use super::*;
             #[test]
             fn test_equals() {
+                #[allow(clippy::multiple_bound_locations)]
                 fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::Equals<B> {}
                 test::<Const<{0 as $t}>, Const<{0 as $t}>>();
                 test::<Const<{0 as $t}>, $t>();
@@ -90,6 +684,7 @@ macro_rules! impl_min {
 
             #[test]
             fn test_equals_result_const() {
+                #[allow(clippy::multiple_bound_locations)]
                 fn const_side_is_const<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() ->bool where A: crate::Equals<B> {
                     <<A as crate::Equals<B>>::ConstSide as crate::MayBeConstAT>::IS_CONST
                 }
@@ -109,6 +704,41 @@ macro_rules! impl_min {
                 assert_eq!({1 as $t}.get_const_side(&{0 as $t}), None);
             }
 
+            #[test]
+            fn test_unify() {
+                use crate::Unify;
+                #[allow(clippy::multiple_bound_locations)]
+                fn output_is_const<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() -> bool where A: Unify<B> {
+                    <<A as Unify<B>>::Output as crate::MayBeConstAT>::IS_CONST
+                }
+                assert!(!output_is_const::<Const<{0 as $t}>, $t>());
+                assert!(!output_is_const::<$t, Const<{0 as $t}>>());
+                assert!(!output_is_const::<Const<{1 as $t}>, Const<{0 as $t}>>());
+
+                assert_eq!(Const::<{0 as $t}>::new().unify(&Const::<{0 as $t}>::new()).map(|value| crate::MayBeConstAT::value(&value)), Some(0 as $t));
+                assert_eq!(Const::<{1 as $t}>::new().unify(&Const::<{0 as $t}>::new()).map(|value| crate::MayBeConstAT::value(&value)), None);
+                assert_eq!(Const::<{0 as $t}>::new().unify(&(0 as $t)), Some(0 as $t));
+                assert_eq!(Const::<{0 as $t}>::new().unify(&(1 as $t)), None);
+                assert_eq!((0 as $t).unify(&Const::<{0 as $t}>::new()), Some(0 as $t));
+                assert_eq!((1 as $t).unify(&Const::<{0 as $t}>::new()), None);
+            }
+
+            #[test]
+            fn test_try_from_value() {
+                use core::convert::TryFrom;
+                assert_eq!(Const::<{1 as $t}>::try_from_value(1 as $t), Ok(Const::<{1 as $t}>::new()));
+                assert_eq!(Const::<{1 as $t}>::try_from_value(0 as $t), Err(crate::ValueMismatch::new(1 as $t, 0 as $t)));
+                assert_eq!(Const::<{1 as $t}>::try_from(1 as $t), Ok(Const::<{1 as $t}>::new()));
+                assert_eq!(Const::<{1 as $t}>::try_from(0 as $t), Err(crate::ValueMismatch::new(1 as $t, 0 as $t)));
+                assert_eq!(Const::<{1 as $t}>::assert_from(1 as $t), Const::<{1 as $t}>::new());
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_assert_from() {
+                Const::<{1 as $t}>::assert_from(0 as $t);
+            }
+
             #[test]
             fn test_is_const() {
                 assert!(<Const<{0 as $t}> as crate::MayBeConstAT>::IS_CONST);
@@ -127,6 +757,33 @@ macro_rules! impl_min {
                 assert!(!{1 as $t}.eq(&{0 as $t}));
             }
 
+            #[test]
+            fn test_ord() {
+                assert_eq!(Const::<{1 as $t}>::new().cmp(&Const::<{1 as $t}>::new()), core::cmp::Ordering::Equal);
+                let mut values = [Const::<{1 as $t}>::new(); 2];
+                values.sort();
+                assert_eq!(values.iter().max(), Some(&Const::<{1 as $t}>::new()));
+            }
+
+            #[test]
+            fn test_hash() {
+                use std::hash::{Hash, Hasher};
+                fn hash<T: Hash>(value: &T) -> u64 {
+                    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                    value.hash(&mut hasher);
+                    hasher.finish()
+                }
+                assert_eq!(hash(&Const::<{1 as $t}>::new()), hash(&{1 as $t}));
+                assert_eq!(hash(&Const::<{<$t>::MAX}>::new()), hash(&<$t>::MAX));
+
+                let mut set = std::collections::HashSet::new();
+                assert!(set.insert(Const::<{1 as $t}>::new()));
+                assert!(!set.insert(Const::<{1 as $t}>::new()));
+                let mut map = std::collections::BTreeMap::new();
+                map.insert(Const::<{1 as $t}>::new(), ());
+                assert!(map.contains_key(&Const::<{1 as $t}>::new()));
+            }
+
             #[test]
             fn test_partial_ord() {
                 assert_eq!(Const::<{1 as $t}>::new().partial_cmp(&Const::<{1 as $t}>::new()), Some(core::cmp::Ordering::Equal));
@@ -148,9 +805,1318 @@ macro_rules! impl_min {
     }
 }
 
-macro_rules! impl_min_out {
-    ($c:tt, $t:tt, $($doc:expr),*) => {
-        $(#[doc = $doc])*
-        pub type $c<const VALUE:$t> = crate::$t::Const<VALUE>;
+macro_rules! impl_min_min_max {
+    ($t:tt) => {
+        #[doc(hidden)]
+        pub struct MinMax<const VALUE1: $t, const VALUE2: $t>;
+
+        impl<const VALUE1: $t, const VALUE2: $t> MinMax<VALUE1, VALUE2> {
+            #[doc(hidden)]
+            pub const MIN: $t = if VALUE1 <= VALUE2 { VALUE1 } else { VALUE2 };
+            #[doc(hidden)]
+            pub const MAX: $t = if VALUE1 >= VALUE2 { VALUE1 } else { VALUE2 };
+        }
+
+        #[doc(hidden)]
+        pub struct Clamped<const VALUE: $t, const LO: $t, const HI: $t>;
+
+        impl<const VALUE: $t, const LO: $t, const HI: $t> Clamped<VALUE, LO, HI> {
+            #[doc(hidden)]
+            pub const VALUE: $t = if VALUE < LO { LO } else if VALUE > HI { HI } else { VALUE };
+        }
+
+        impl_min_min_max!(@op $t, Min, minimum, min, MIN);
+        impl_min_min_max!(@op $t, Max, maximum, max, MAX);
+
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $t, const LO: $t, const HI: $t> crate::Clamp<Const<LO>, Const<HI>> for Const<VALUE> {
+            const ASSERT: () = assert!(LO <= HI, "constraint `lo <= hi` of `Clamp` is not satisfied");
+            type Output = $t;
+            #[inline(always)]
+            fn clamped(self, _: Const<LO>, _: Const<HI>) -> $t {
+                let () = <Self as crate::Clamp<Const<LO>, Const<HI>>>::ASSERT;
+                Clamped::<VALUE, LO, HI>::VALUE
+            }
+        }
+        impl<const LO: $t, const HI: $t> crate::Clamp<Const<LO>, Const<HI>> for $t {
+            const ASSERT: () = assert!(LO <= HI, "constraint `lo <= hi` of `Clamp` is not satisfied");
+            type Output = $t;
+            #[inline(always)]
+            fn clamped(self, _: Const<LO>, _: Const<HI>) -> $t {
+                let () = <Self as crate::Clamp<Const<LO>, Const<HI>>>::ASSERT;
+                Ord::clamp(self, LO, HI)
+            }
+        }
+        impl_min_min_max!(@clamp $t, [const VALUE: $t, const LO: $t], Const<VALUE>, Const<LO>, $t);
+        impl_min_min_max!(@clamp $t, [const VALUE: $t, const HI: $t], Const<VALUE>, $t, Const<HI>);
+        impl_min_min_max!(@clamp $t, [const VALUE: $t], Const<VALUE>, $t, $t);
+        impl_min_min_max!(@clamp $t, [const LO: $t], $t, Const<LO>, $t);
+        impl_min_min_max!(@clamp $t, [const HI: $t], $t, $t, Const<HI>);
+        impl_min_min_max!(@clamp $t, [], $t, $t, $t);
+
+        #[cfg(test)]
+        mod test_min_max {
+            use super::*;
+            #[test]
+            fn test_min_max() {
+                assert_eq!(crate::min(Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new()), 1 as $t);
+                assert_eq!(crate::min(Const::<{1 as $t}>::new(), 0 as $t), 0 as $t);
+                assert_eq!(crate::min(2 as $t, Const::<{1 as $t}>::new()), 1 as $t);
+                assert_eq!(crate::min(2 as $t, 1 as $t), 1 as $t);
+                assert_eq!(crate::max(Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new()), 2 as $t);
+                assert_eq!(crate::max(Const::<{1 as $t}>::new(), 0 as $t), 1 as $t);
+                assert_eq!(crate::max(2 as $t, Const::<{1 as $t}>::new()), 2 as $t);
+                assert_eq!(crate::max(2 as $t, 1 as $t), 2 as $t);
+            }
+
+            #[test]
+            fn test_clamp() {
+                fn test<X, L, H>() where X: crate::Clamp<L, H> {
+                    let () = <X as crate::Clamp<L, H>>::ASSERT;
+                }
+                test::<Const<{3 as $t}>, Const<{1 as $t}>, Const<{2 as $t}>>();
+                test::<$t, Const<{1 as $t}>, Const<{1 as $t}>>();
+                test::<$t, Const<{2 as $t}>, $t>();
+                test::<$t, $t, $t>();
+                // test::<Const<{3 as $t}>, Const<{2 as $t}>, Const<{1 as $t}>>(); // <-Compile Error!
+                // test::<$t, Const<{2 as $t}>, Const<{1 as $t}>>(); // <-Compile Error!
+
+                assert_eq!(crate::clamp(Const::<{3 as $t}>::new(), Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new()), 2 as $t);
+                assert_eq!(crate::clamp(0 as $t, Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new()), 1 as $t);
+                assert_eq!(crate::clamp(Const::<{1 as $t}>::new(), 0 as $t, Const::<{2 as $t}>::new()), 1 as $t);
+                assert_eq!(crate::clamp(Const::<{3 as $t}>::new(), Const::<{1 as $t}>::new(), 2 as $t), 2 as $t);
+                assert_eq!(crate::clamp(Const::<{0 as $t}>::new(), 1 as $t, 2 as $t), 1 as $t);
+                assert_eq!(crate::clamp(3 as $t, Const::<{1 as $t}>::new(), 2 as $t), 2 as $t);
+                assert_eq!(crate::clamp(0 as $t, 1 as $t, Const::<{2 as $t}>::new()), 1 as $t);
+                assert_eq!(crate::clamp(1 as $t, 0 as $t, 2 as $t), 1 as $t);
+            }
+
+            #[test]
+            #[should_panic]
+            fn test_clamp_runtime_bounds() {
+                crate::clamp(Const::<{1 as $t}>::new(), 2 as $t, 1 as $t);
+            }
+        }
+    };
+    (@op $t:tt, $trait:ident, $method:ident, $ord:ident, $operand:ident) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> crate::$trait<Const<VALUE2>> for Const<VALUE1> {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE2>) -> $t {
+                MinMax::<VALUE1, VALUE2>::$operand
+            }
+        }
+        impl<const VALUE: $t> crate::$trait<$t> for Const<VALUE> {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, rhs: $t) -> $t {
+                Ord::$ord(VALUE, rhs)
+            }
+        }
+        impl<const VALUE: $t> crate::$trait<Const<VALUE>> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE>) -> $t {
+                Ord::$ord(self, VALUE)
+            }
+        }
+        impl crate::$trait<$t> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, rhs: $t) -> $t {
+                Ord::$ord(self, rhs)
+            }
+        }
+    };
+    (@clamp $t:tt, [$($generics:tt)*], $x:ty, $lo:ty, $hi:ty) => {
+        impl<$($generics)*> crate::Clamp<$lo, $hi> for $x {
+            type Output = $t;
+            #[inline(always)]
+            fn clamped(self, lo: $lo, hi: $hi) -> $t {
+                Ord::clamp(crate::MayBeConstAT::value(&self), crate::MayBeConstAT::value(&lo), crate::MayBeConstAT::value(&hi))
+            }
+        }
+    };
+}
+
+macro_rules! impl_min_ord {
+    ($t:tt) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Lt<Const<VALUE2>> for Const<VALUE1> {
+            const ASSERT: () = assert!(VALUE1 < VALUE2, "constraint `Lt` is not satisfied");
+            impl_ordering_check!(Lt, Const<VALUE2>, const);
+        }
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Gt<Const<VALUE2>> for Const<VALUE1> {
+            const ASSERT: () = assert!(VALUE1 > VALUE2, "constraint `Gt` is not satisfied");
+            impl_ordering_check!(Gt, Const<VALUE2>, const);
+        }
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Leq<Const<VALUE2>> for Const<VALUE1> {
+            const ASSERT: () = assert!(VALUE1 <= VALUE2, "constraint `Leq` is not satisfied");
+            impl_ordering_check!(Leq, Const<VALUE2>, const);
+        }
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> crate::Geq<Const<VALUE2>> for Const<VALUE1> {
+            const ASSERT: () = assert!(VALUE1 >= VALUE2, "constraint `Geq` is not satisfied");
+            impl_ordering_check!(Geq, Const<VALUE2>, const);
+        }
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> crate::NotEquals<Const<VALUE2>> for Const<VALUE1> {
+            const ASSERT: () = assert!(VALUE1 != VALUE2, "constraint `NotEquals` is not satisfied");
+            impl_ordering_check!(NotEquals::check_distinct, Const<VALUE2>, const);
+        }
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $t, const LO: $t, const HI: $t> crate::InRange<Const<LO>, Const<HI>> for Const<VALUE> {
+            const ASSERT: () = assert!(LO <= VALUE && VALUE <= HI, "constraint `InRange` is not satisfied");
+            impl_in_range_check!(Const<LO>, Const<HI>, const);
+        }
+
+        impl_min_min_max!($t);
+
+        #[cfg(test)]
+        mod test_min_ord {
+            use super::*;
+            #[test]
+            fn test_lt() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::Lt<B> {
+                    let () = <A as crate::Lt<B>>::ASSERT;
+                }
+                test::<Const<{1 as $t}>, Const<{2 as $t}>>();
+                test::<Const<{1 as $t}>, $t>();
+                test::<$t, Const<{1 as $t}>>();
+                test::<$t, $t>();
+                // test::<Const<{1 as $t}>, Const<{1 as $t}>>(); // <-Compile Error!
+                // test::<Const<{2 as $t}>, Const<{1 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_gt() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::Gt<B> {
+                    let () = <A as crate::Gt<B>>::ASSERT;
+                }
+                test::<Const<{2 as $t}>, Const<{1 as $t}>>();
+                test::<Const<{1 as $t}>, $t>();
+                test::<$t, Const<{1 as $t}>>();
+                test::<$t, $t>();
+                // test::<Const<{1 as $t}>, Const<{1 as $t}>>(); // <-Compile Error!
+                // test::<Const<{1 as $t}>, Const<{2 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_leq() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::Leq<B> {
+                    let () = <A as crate::Leq<B>>::ASSERT;
+                }
+                test::<Const<{1 as $t}>, Const<{1 as $t}>>();
+                test::<Const<{1 as $t}>, Const<{2 as $t}>>();
+                test::<Const<{1 as $t}>, $t>();
+                test::<$t, Const<{1 as $t}>>();
+                test::<$t, $t>();
+                // test::<Const<{2 as $t}>, Const<{1 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_not_equals() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::NotEquals<B> {
+                    let () = <A as crate::NotEquals<B>>::ASSERT;
+                }
+                test::<Const<{1 as $t}>, Const<{2 as $t}>>();
+                test::<Const<{2 as $t}>, Const<{1 as $t}>>();
+                test::<Const<{1 as $t}>, $t>();
+                test::<$t, Const<{1 as $t}>>();
+                test::<$t, $t>();
+                // test::<Const<{1 as $t}>, Const<{1 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_check_distinct() {
+                use crate::NotEquals;
+                let proof = Const::<{1 as $t}>::new().check_distinct(&Const::<{2 as $t}>::new()).unwrap();
+                assert!(proof.lhs_is_const() && proof.rhs_is_const());
+                let proof = Const::<{1 as $t}>::new().check_distinct(&{2 as $t}).unwrap();
+                assert!(proof.lhs_is_const() && !proof.rhs_is_const());
+                let proof = {2 as $t}.check_distinct(&Const::<{1 as $t}>::new()).unwrap();
+                assert!(!proof.lhs_is_const() && proof.rhs_is_const());
+
+                assert!(Const::<{1 as $t}>::new().check_distinct(&{1 as $t}).is_err());
+                let error = {1 as $t}.check_distinct(&Const::<{1 as $t}>::new()).unwrap_err();
+                assert_eq!(*error.lhs(), 1 as $t);
+                assert_eq!(*error.rhs(), 1 as $t);
+                // Const::<{1 as $t}>::new().check_distinct(&Const::<{1 as $t}>::new()); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_in_range() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, L: crate::MayBeConst<$t>, H: crate::MayBeConst<$t>>() where A: crate::InRange<L, H> {
+                    let () = <A as crate::InRange<L, H>>::ASSERT;
+                }
+                test::<Const<{1 as $t}>, Const<{1 as $t}>, Const<{2 as $t}>>();
+                test::<Const<{2 as $t}>, Const<{1 as $t}>, Const<{2 as $t}>>();
+                test::<Const<{1 as $t}>, Const<{1 as $t}>, $t>();
+                test::<Const<{1 as $t}>, $t, Const<{1 as $t}>>();
+                test::<Const<{1 as $t}>, $t, $t>();
+                test::<$t, Const<{1 as $t}>, Const<{2 as $t}>>();
+                test::<$t, Const<{1 as $t}>, $t>();
+                test::<$t, $t, Const<{1 as $t}>>();
+                test::<$t, $t, $t>();
+                // test::<Const<{0 as $t}>, Const<{1 as $t}>, Const<{2 as $t}>>(); // <-Compile Error!
+                // test::<Const<{3 as $t}>, Const<{1 as $t}>, Const<{2 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_check_in_range() {
+                use crate::InRange;
+                assert!(Const::<{1 as $t}>::new().check_in_range(&Const::<{1 as $t}>::new(), &Const::<{2 as $t}>::new()).is_ok());
+                assert!(Const::<{2 as $t}>::new().check_in_range(&Const::<{1 as $t}>::new(), &{2 as $t}).is_ok());
+                assert!(Const::<{2 as $t}>::new().check_in_range(&{1 as $t}, &Const::<{1 as $t}>::new()).is_err());
+                assert!(Const::<{0 as $t}>::new().check_in_range(&{1 as $t}, &{2 as $t}).is_err());
+                assert!({1 as $t}.check_in_range(&Const::<{1 as $t}>::new(), &Const::<{2 as $t}>::new()).is_ok());
+                assert!({0 as $t}.check_in_range(&Const::<{1 as $t}>::new(), &{2 as $t}).is_err());
+                assert!({3 as $t}.check_in_range(&{1 as $t}, &Const::<{2 as $t}>::new()).is_err());
+
+                let error = {3 as $t}.check_in_range(&Const::<{1 as $t}>::new(), &Const::<{2 as $t}>::new()).unwrap_err();
+                assert_eq!(*error.value(), 3 as $t);
+                assert_eq!(*error.lo(), 1 as $t);
+                assert_eq!(*error.hi(), 2 as $t);
+                // Const::<{3 as $t}>::new().check_in_range(&Const::<{1 as $t}>::new(), &Const::<{2 as $t}>::new()); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_check() {
+                use crate::{Lt, Gt, Leq, Geq};
+                let proof = Lt::check(&Const::<{1 as $t}>::new(), &Const::<{2 as $t}>::new()).unwrap();
+                assert!(proof.lhs_is_const() && proof.rhs_is_const());
+                let proof = Lt::check(&Const::<{1 as $t}>::new(), &{2 as $t}).unwrap();
+                assert!(proof.lhs_is_const() && !proof.rhs_is_const());
+                let proof = Lt::check(&{1 as $t}, &Const::<{2 as $t}>::new()).unwrap();
+                assert!(!proof.lhs_is_const() && proof.rhs_is_const());
+
+                assert!(Lt::check(&Const::<{1 as $t}>::new(), &{1 as $t}).is_err());
+                assert!(Lt::check(&{2 as $t}, &Const::<{1 as $t}>::new()).is_err());
+                assert!(Gt::check(&Const::<{2 as $t}>::new(), &Const::<{1 as $t}>::new()).is_ok());
+                assert!(Gt::check(&Const::<{1 as $t}>::new(), &{1 as $t}).is_err());
+                assert!(Gt::check(&{1 as $t}, &Const::<{2 as $t}>::new()).is_err());
+                assert!(Leq::check(&Const::<{1 as $t}>::new(), &Const::<{1 as $t}>::new()).is_ok());
+                assert!(Leq::check(&Const::<{2 as $t}>::new(), &{1 as $t}).is_err());
+                assert!(Leq::check(&{2 as $t}, &Const::<{1 as $t}>::new()).is_err());
+                assert!(Geq::check(&Const::<{1 as $t}>::new(), &Const::<{1 as $t}>::new()).is_ok());
+                assert!(Geq::check(&Const::<{1 as $t}>::new(), &{2 as $t}).is_err());
+                assert!(Geq::check(&{1 as $t}, &Const::<{2 as $t}>::new()).is_err());
+
+                let error = Lt::check(&Const::<{2 as $t}>::new(), &{1 as $t}).unwrap_err();
+                assert_eq!(*error.lhs(), 2 as $t);
+                assert_eq!(*error.rhs(), 1 as $t);
+                // Lt::check(&Const::<{2 as $t}>::new(), &Const::<{1 as $t}>::new()); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_geq() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::Geq<B> {
+                    let () = <A as crate::Geq<B>>::ASSERT;
+                }
+                test::<Const<{1 as $t}>, Const<{1 as $t}>>();
+                test::<Const<{2 as $t}>, Const<{1 as $t}>>();
+                test::<Const<{1 as $t}>, $t>();
+                test::<$t, Const<{1 as $t}>>();
+                test::<$t, $t>();
+                // test::<Const<{1 as $t}>, Const<{2 as $t}>>(); // <-Compile Error!
+            }
+        }
+    }
+}
+
+macro_rules! impl_min_props {
+    (char, $t:tt) => {};
+    ($kind:ident, $t:tt) => {
+        impl<const VALUE: $t> Const<VALUE> {
+            /// The constant compared by its mathematical value.
+            #[doc(hidden)]
+            #[allow(unused_comparisons)]
+            pub const ANY_INT: crate::AnyInt = any_int!(VALUE);
+
+            /// The constant as a nonzero type.
+            /// Evaluating it fails to compile if the value is zero.
+            #[doc(hidden)]
+            pub const NONZERO: nonzero!($t) = match <nonzero!($t)>::new(VALUE) {
+                Some(value) => value,
+                None => panic!("constraint `NonZero` is not satisfied"),
+            };
+        }
+
+        impl<const VALUE: $t> crate::MayBeConstNonZero for Const<VALUE> where Const<VALUE>: crate::NonZero {
+            type Int = $t;
+            type NonZero = nonzero!($t);
+            const IS_CONST: bool = true;
+            #[inline(always)]
+            fn get(&self) -> $t {
+                let () = <Const<VALUE> as crate::NonZero>::ASSERT;
+                VALUE
+            }
+            #[inline(always)]
+            fn nonzero(&self) -> nonzero!($t) {
+                (*self).into()
+            }
+        }
+
+        impl<const VALUE: $t> core::convert::From<Const<VALUE>> for nonzero!($t) where Const<VALUE>: crate::NonZero {
+            #[inline(always)]
+            fn from(_: Const<VALUE>) -> Self {
+                let () = <Const<VALUE> as crate::NonZero>::ASSERT;
+                Const::<VALUE>::NONZERO
+            }
+        }
+
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $t> crate::NonZero for Const<VALUE> {
+            const ASSERT: () = assert!(VALUE != 0, "constraint `NonZero` is not satisfied");
+            impl_property_check!(NonZero, const);
+        }
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $t> crate::PowerOfTwo for Const<VALUE> {
+            const ASSERT: () = assert!(VALUE > 0 && VALUE & (VALUE - 1) == 0, "constraint `PowerOfTwo` is not satisfied");
+            impl_property_check!(PowerOfTwo, const);
+        }
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $t> crate::Even for Const<VALUE> {
+            const ASSERT: () = assert!(VALUE % 2 == 0, "constraint `Even` is not satisfied");
+            impl_property_check!(Even, const);
+        }
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $t> crate::Odd for Const<VALUE> {
+            const ASSERT: () = assert!(VALUE % 2 != 0, "constraint `Odd` is not satisfied");
+            impl_property_check!(Odd, const);
+        }
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $t, const M: $t> crate::MultipleOf<Const<M>> for Const<VALUE> {
+            const ASSERT: () = assert!(Operands::<VALUE, M>::MULTIPLE_OF, "constraint `MultipleOf` is not satisfied");
+            impl_multiple_of_check!(Const<M>, const);
+        }
+        impl<const VALUE: $t> crate::MultipleOf<$t> for Const<VALUE> {
+            impl_multiple_of_check!($t);
+        }
+        impl<const M: $t> crate::MultipleOf<Const<M>> for $t {
+            impl_multiple_of_check!(Const<M>);
+        }
+
+        impl_min_sign!($kind, $t);
+
+        #[cfg(test)]
+        mod test_min_props {
+            use super::*;
+            #[test]
+            fn test_non_zero() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>>() where A: crate::NonZero {
+                    let () = <A as crate::NonZero>::ASSERT;
+                }
+                test::<Const<{1 as $t}>>();
+                test::<Const<{<$t>::MAX}>>();
+                test::<$t>();
+                // test::<Const<{0 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_may_be_const_int() {
+                use crate::MayBeConstInt;
+                fn sum<A: MayBeConstInt, B: MayBeConstInt<Int=A::Int>>(a: A, b: B) -> A::Int {
+                    a.value() + b.value()
+                }
+                assert_eq!(sum(Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new()), 3 as $t);
+                assert_eq!(sum(Const::<{1 as $t}>::new(), 2 as $t), 3 as $t);
+                assert_eq!(sum(1 as $t, 2 as $t), 3 as $t);
+
+                assert_eq!(<Const<{0 as $t}> as MayBeConstInt>::BITS, <$t>::BITS);
+                assert_eq!(<Const<{0 as $t}> as MayBeConstInt>::MAX, <$t>::MAX);
+                assert_eq!(<$t as MayBeConstInt>::BITS, (core::mem::size_of::<$t>() * 8) as u32);
+                assert_eq!(<$t as MayBeConstInt>::MIN, <$t>::MIN);
+                assert_eq!(<$t as MayBeConstInt>::MAX, <$t>::MAX);
+                assert_eq!(<$t as MayBeConstInt>::SIGNED, <$t>::MIN != 0);
+
+                assert_eq!(MayBeConstInt::checked_add(Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new()).map(|sum| crate::MayBeConstAT::value(&sum)), Some(3 as $t));
+                assert_eq!(MayBeConstInt::checked_add(Const::<{1 as $t}>::new(), 2 as $t), Some(3 as $t));
+                assert_eq!(MayBeConstInt::checked_add(<$t>::MAX, Const::<{1 as $t}>::new()), None);
+                assert_eq!(MayBeConstInt::checked_add(<$t>::MAX, 1 as $t), None);
+                assert_eq!(MayBeConstInt::checked_div(1 as $t, 0 as $t), None);
+                assert_eq!(crate::MayBeConstAT::value(&MayBeConstInt::wrapping_add(Const::<{<$t>::MAX}>::new(), Const::<{1 as $t}>::new())), <$t>::MIN);
+                assert_eq!(MayBeConstInt::wrapping_add(<$t>::MAX, 1 as $t), <$t>::MIN);
+                assert_eq!(MayBeConstInt::saturating_add(<$t>::MAX, Const::<{1 as $t}>::new()), <$t>::MAX);
+                assert_eq!(MayBeConstInt::count_ones(&Const::<{7 as $t}>::new()), 3);
+                assert_eq!(MayBeConstInt::count_zeros(&(0 as $t)), (core::mem::size_of::<$t>() * 8) as u32);
+                assert_eq!(MayBeConstInt::leading_zeros(&(1 as $t)), (core::mem::size_of::<$t>() * 8) as u32 - 1);
+                assert_eq!(MayBeConstInt::trailing_zeros(&(8 as $t)), 3);
+            }
+
+            #[test]
+            fn test_into_nonzero() {
+                fn test<A: crate::MayBeConst<$t> + Into<nonzero!($t)>>(value: A) -> nonzero!($t) {
+                    value.into()
+                }
+                assert_eq!(test(Const::<{1 as $t}>::new()).get(), 1 as $t);
+                assert_eq!(test(Const::<{<$t>::MAX}>::new()).get(), <$t>::MAX);
+                // test(Const::<{0 as $t}>::new()); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_may_be_const_nonzero() {
+                use crate::MayBeConstNonZero;
+                fn test<A: MayBeConstNonZero<NonZero=nonzero!($t)>>(value: A) -> nonzero!($t) {
+                    value.nonzero()
+                }
+                assert_eq!(test(Const::<{1 as $t}>::new()).get(), 1 as $t);
+                assert_eq!(test(<nonzero!($t)>::new(1 as $t).unwrap()).get(), 1 as $t);
+                assert_eq!(MayBeConstNonZero::get(&Const::<{<$t>::MAX}>::new()), <$t>::MAX);
+                assert!(<Const<{1 as $t}> as MayBeConstNonZero>::IS_CONST);
+                // test(Const::<{0 as $t}>::new()); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_power_of_two() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>>() where A: crate::PowerOfTwo {
+                    let () = <A as crate::PowerOfTwo>::ASSERT;
+                }
+                test::<Const<{1 as $t}>>();
+                test::<Const<{64 as $t}>>();
+                test::<$t>();
+                // test::<Const<{0 as $t}>>(); // <-Compile Error!
+                // test::<Const<{12 as $t}>>(); // <-Compile Error!
+                // test::<Const<{<$t>::MIN}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_even_odd() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn even<A: crate::MayBeConst<$t>>() where A: crate::Even {
+                    let () = <A as crate::Even>::ASSERT;
+                }
+                #[allow(clippy::multiple_bound_locations)]
+                fn odd<A: crate::MayBeConst<$t>>() where A: crate::Odd {
+                    let () = <A as crate::Odd>::ASSERT;
+                }
+                even::<Const<{0 as $t}>>();
+                even::<Const<{2 as $t}>>();
+                even::<$t>();
+                odd::<Const<{1 as $t}>>();
+                odd::<Const<{<$t>::MAX}>>();
+                odd::<$t>();
+                // even::<Const<{1 as $t}>>(); // <-Compile Error!
+                // odd::<Const<{2 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_multiple_of() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, M: crate::MayBeConst<$t>>() where A: crate::MultipleOf<M> {
+                    let () = <A as crate::MultipleOf<M>>::ASSERT;
+                }
+                test::<Const<{12 as $t}>, Const<{4 as $t}>>();
+                test::<Const<{0 as $t}>, Const<{0 as $t}>>();
+                test::<Const<{<$t>::MIN}>, Const<{<$t>::MIN}>>();
+                test::<Const<{12 as $t}>, $t>();
+                test::<$t, Const<{4 as $t}>>();
+                test::<$t, $t>();
+                // test::<Const<{10 as $t}>, Const<{4 as $t}>>(); // <-Compile Error!
+                // test::<Const<{4 as $t}>, Const<{0 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_check() {
+                use crate::{Even, MultipleOf, NonZero, Odd, PowerOfTwo};
+                assert!(NonZero::check(&Const::<{1 as $t}>::new()).is_ok());
+                assert!(PowerOfTwo::check(&Const::<{64 as $t}>::new()).is_ok());
+                assert!(Even::check(&Const::<{2 as $t}>::new()).is_ok());
+                assert!(Odd::check(&Const::<{3 as $t}>::new()).is_ok());
+                assert!(MultipleOf::check(&Const::<{12 as $t}>::new(), &Const::<{4 as $t}>::new()).is_ok());
+
+                assert!(MultipleOf::check(&Const::<{12 as $t}>::new(), &{4 as $t}).is_ok());
+                assert!(MultipleOf::check(&Const::<{12 as $t}>::new(), &{5 as $t}).is_err());
+                assert!(MultipleOf::check(&{12 as $t}, &Const::<{4 as $t}>::new()).is_ok());
+                let error = MultipleOf::check(&{10 as $t}, &Const::<{4 as $t}>::new()).unwrap_err();
+                assert_eq!(*error.value(), 10 as $t);
+                assert_eq!(*error.divisor(), 4 as $t);
+                // NonZero::check(&Const::<{0 as $t}>::new()); // <-Compile Error!
+            }
+        }
+    };
+}
+
+macro_rules! impl_min_sign {
+    (unsigned, $t:tt) => {};
+    (signed, $t:tt) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $t> crate::Positive for Const<VALUE> {
+            const ASSERT: () = assert!(VALUE > 0, "constraint `Positive` is not satisfied");
+            impl_property_check!(Positive::check_sign, const);
+        }
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $t> crate::NonNegative for Const<VALUE> {
+            const ASSERT: () = assert!(VALUE >= 0, "constraint `NonNegative` is not satisfied");
+            impl_property_check!(NonNegative::check_sign, const);
+        }
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $t> crate::Negative for Const<VALUE> {
+            const ASSERT: () = assert!(VALUE < 0, "constraint `Negative` is not satisfied");
+            impl_property_check!(Negative::check_sign, const);
+        }
+
+        #[cfg(test)]
+        mod test_min_sign {
+            use super::*;
+            #[test]
+            fn test_positive() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>>() where A: crate::Positive {
+                    let () = <A as crate::Positive>::ASSERT;
+                }
+                test::<Const<{1 as $t}>>();
+                test::<Const<{<$t>::MAX}>>();
+                test::<$t>();
+                // test::<Const<{0 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_non_negative() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>>() where A: crate::NonNegative {
+                    let () = <A as crate::NonNegative>::ASSERT;
+                }
+                test::<Const<{0 as $t}>>();
+                test::<Const<{<$t>::MAX}>>();
+                test::<$t>();
+                // test::<Const<{-1 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_negative() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>>() where A: crate::Negative {
+                    let () = <A as crate::Negative>::ASSERT;
+                }
+                test::<Const<{-1 as $t}>>();
+                test::<Const<{<$t>::MIN}>>();
+                test::<$t>();
+                // test::<Const<{0 as $t}>>(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_check_sign() {
+                use crate::{Negative, NonNegative, Positive};
+                assert!(Positive::check_sign(&Const::<{1 as $t}>::new()).is_ok());
+                assert!(NonNegative::check_sign(&Const::<{0 as $t}>::new()).is_ok());
+                assert!(Negative::check_sign(&Const::<{-1 as $t}>::new()).is_ok());
+                // Negative::check_sign(&Const::<{1 as $t}>::new()); // <-Compile Error!
+            }
+        }
+    };
+}
+
+/// Compile-time check of the conversion from a non-negative signed constant into an unsigned constant.
+#[doc(hidden)]
+pub trait ConstUnsigned<T> {
+    const ASSERT: ();
+}
+
+macro_rules! impl_min_unsigned {
+    ($from:tt, $to:tt) => {
+        impl<const VALUE: $from, const UNSIGNED: $to> crate::ConstUnsigned<crate::$to::Const<UNSIGNED>> for crate::$from::Const<VALUE> {
+            const ASSERT: () = {
+                assert!(VALUE >= 0, "constraint `NonNegative` is not satisfied");
+                assert!(VALUE as $to == UNSIGNED, "the unsigned constant does not have the same value");
+            };
+        }
+
+        /// Convert a non-negative constant into the unsigned constant with the same value.
+        ///
+        /// It is implemented for every `UNSIGNED`, so the target must be named by the caller,
+        /// and a negative `VALUE` or an `UNSIGNED` with another value fails to compile when `from` is evaluated.
+        impl<const VALUE: $from, const UNSIGNED: $to> core::convert::From<crate::$from::Const<VALUE>> for crate::$to::Const<UNSIGNED>
+            where crate::$from::Const<VALUE>: crate::NonNegative {
+            #[inline(always)]
+            fn from(_: crate::$from::Const<VALUE>) -> Self {
+                let () = <crate::$from::Const<VALUE> as crate::ConstUnsigned<Self>>::ASSERT;
+                Self::new()
+            }
+        }
+    };
+}
+
+macro_rules! impl_min_out {
+    ($c:tt, $t:tt, $($doc:expr),*) => {
+        $(#[doc = $doc])*
+        pub type $c<const VALUE:$t> = crate::$t::Const<VALUE>;
+    }
+}
+
+macro_rules! impl_min_binary_op {
+    ($t:tt, $trait:ident, $method:ident, $op:tt, $operand:ident) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> core::ops::$trait<Const<VALUE2>> for Const<VALUE1> {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE2>) -> $t {
+                Operands::<VALUE1, VALUE2>::$operand
+            }
+        }
+        impl<const VALUE: $t> core::ops::$trait<$t> for Const<VALUE> {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, rhs: $t) -> $t {
+                VALUE $op rhs
+            }
+        }
+        impl<const VALUE: $t> core::ops::$trait<Const<VALUE>> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE>) -> $t {
+                self $op VALUE
+            }
+        }
+    }
+}
+
+macro_rules! impl_min_shift_op {
+    ($t:tt, $trait:ident, $method:ident, $op:tt, $operand:ident) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> core::ops::$trait<Const<VALUE2>> for Const<VALUE1> {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE2>) -> $t {
+                Operands::<VALUE1, VALUE2>::$operand
+            }
+        }
+        impl<const VALUE: $t> core::ops::$trait<$t> for Const<VALUE> {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, rhs: $t) -> $t {
+                VALUE $op rhs
+            }
+        }
+        impl<const VALUE: $t> core::ops::$trait<Const<VALUE>> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE>) -> $t {
+                self $op Const::<VALUE>::SHIFT_AMOUNT
+            }
+        }
+    }
+}
+
+macro_rules! impl_min_checked_op {
+    ($t:tt, $trait:ident, $method:ident, $operand:ident) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> crate::$trait<Const<VALUE2>> for Const<VALUE1> {
+            type Output = Option<$t>;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE2>) -> Option<$t> {
+                Some(Operands::<VALUE1, VALUE2>::$operand)
+            }
+        }
+        impl_min_checked_op!(@runtime $t, $trait, $method, $method);
+        impl<const VALUE: $t> crate::$trait<Const<VALUE>> for $t {
+            type Output = Option<$t>;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE>) -> Option<$t> {
+                <$t>::$method(self, VALUE)
+            }
+        }
+    };
+    ($t:tt, $trait:ident, $method:ident, $checked:ident, $operand:ident) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> crate::$trait<Const<VALUE2>> for Const<VALUE1> {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE2>) -> $t {
+                Operands::<VALUE1, VALUE2>::$operand
+            }
+        }
+        impl_min_checked_op!(@runtime $t, $trait, $method, $checked);
+    };
+    (@runtime $t:tt, $trait:ident, $method:ident, $checked:ident) => {
+        impl<const VALUE: $t> crate::$trait<$t> for Const<VALUE> {
+            type Output = Option<$t>;
+            #[inline(always)]
+            fn $method(self, rhs: $t) -> Option<$t> {
+                <$t>::$checked(VALUE, rhs)
+            }
+        }
+        impl crate::$trait<$t> for $t {
+            type Output = Option<$t>;
+            #[inline(always)]
+            fn $method(self, rhs: $t) -> Option<$t> {
+                <$t>::$checked(self, rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_min_safe_div {
+    (unsigned, $t:tt) => {
+        impl<const VALUE: $t> crate::SafeDiv<Const<VALUE>> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn safe_div(self, _: Const<VALUE>) -> $t {
+                let divisor = Const::<VALUE>::NONZERO;
+                if divisor.get().is_power_of_two() {
+                    self >> divisor.trailing_zeros()
+                } else {
+                    self / divisor
+                }
+            }
+        }
+        impl<const VALUE: $t> crate::SafeRem<Const<VALUE>> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn safe_rem(self, _: Const<VALUE>) -> $t {
+                let divisor = Const::<VALUE>::NONZERO;
+                if divisor.get().is_power_of_two() {
+                    self & (divisor.get() - 1)
+                } else {
+                    self % divisor
+                }
+            }
+        }
+        impl_min_safe_div!(@common $t);
+    };
+    (signed, $t:tt) => {
+        impl<const VALUE: $t> Const<VALUE> {
+            /// The constant as a divisor which cannot overflow.
+            /// Evaluating it fails to compile if the value is zero or `-1`.
+            #[doc(hidden)]
+            pub const SAFE_DIVISOR: $t = match VALUE {
+                0 => panic!("constraint `NonZero` is not satisfied"),
+                -1 => panic!("the division of `MIN` by `-1` overflows"),
+                _ => VALUE,
+            };
+        }
+        impl<const VALUE: $t> crate::SafeDiv<Const<VALUE>> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn safe_div(self, _: Const<VALUE>) -> $t {
+                self / Const::<VALUE>::SAFE_DIVISOR
+            }
+        }
+        impl<const VALUE: $t> crate::SafeRem<Const<VALUE>> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn safe_rem(self, _: Const<VALUE>) -> $t {
+                self % Const::<VALUE>::SAFE_DIVISOR
+            }
+        }
+        impl_min_safe_div!(@common $t);
+
+        #[cfg(test)]
+        mod test_min_safe_div {
+            use super::*;
+            #[test]
+            fn test_safe_div_signed() {
+                use crate::{SafeDiv, SafeRem};
+                assert_eq!(SafeDiv::safe_div(-100 as $t, Const::<{8 as $t}>::new()), -12 as $t);
+                assert_eq!(SafeRem::safe_rem(-100 as $t, Const::<{8 as $t}>::new()), -4 as $t);
+                assert_eq!(SafeDiv::safe_div(<$t>::MIN, -1 as $t), None);
+                assert_eq!(SafeRem::safe_rem(<$t>::MIN, -1 as $t), None);
+                // SafeDiv::safe_div(<$t>::MIN, Const::<{-1 as $t}>::new()); // <-Compile Error!
+                // SafeRem::safe_rem(<$t>::MIN, Const::<{-1 as $t}>::new()); // <-Compile Error!
+            }
+        }
+    };
+    (@common $t:tt) => {
+        impl_min_checked_op!($t, SafeDiv, safe_div, checked_div, DIV);
+        impl_min_checked_op!($t, SafeRem, safe_rem, checked_rem, REM);
+    };
+}
+
+macro_rules! impl_min_wrapping_op {
+    ($t:tt, $trait:ident, $method:ident) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $t, const VALUE2: $t> crate::$trait<Const<VALUE2>> for Const<VALUE1> {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE2>) -> $t {
+                <$t>::$method(VALUE1, VALUE2)
+            }
+        }
+        impl<const VALUE: $t> crate::$trait<$t> for Const<VALUE> {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, rhs: $t) -> $t {
+                <$t>::$method(VALUE, rhs)
+            }
+        }
+        impl<const VALUE: $t> crate::$trait<Const<VALUE>> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, _: Const<VALUE>) -> $t {
+                <$t>::$method(self, VALUE)
+            }
+        }
+        impl crate::$trait<$t> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self, rhs: $t) -> $t {
+                <$t>::$method(self, rhs)
+            }
+        }
+    }
+}
+
+macro_rules! impl_min_unary_op {
+    ($t:tt, $trait:ident, $method:ident, $operand:ident) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $t> core::ops::$trait for Const<VALUE> {
+            type Output = $t;
+            #[inline(always)]
+            fn $method(self) -> $t {
+                Const::<VALUE>::$operand
+            }
+        }
+    }
+}
+
+macro_rules! impl_min_neg {
+    (unsigned, $t:tt) => {};
+    (signed, $t:tt) => {
+        impl<const VALUE: $t> Const<VALUE> {
+            /// The negation of this constant.
+            /// Evaluating it fails to compile if the negation overflows.
+            #[doc(hidden)]
+            pub const NEG: $t = match VALUE.checked_neg() {
+                Some(value) => value,
+                None => panic!("attempt to negate with overflow"),
+            };
+        }
+
+        impl_min_unary_op!($t, Neg, neg, NEG);
+
+        #[cfg(test)]
+        mod test_min_neg {
+            use super::*;
+            #[test]
+            fn test_neg() {
+                assert_eq!(-Const::<{1 as $t}>::new(), -1 as $t);
+                assert_eq!(-Const::<{-1 as $t}>::new(), 1 as $t);
+                // -Const::<{<$t>::MIN}>::new(); // <-Compile Error!
+            }
+        }
+    };
+}
+
+macro_rules! impl_min_ops {
+    (char, $t:tt) => {};
+    ($kind:ident, $t:tt) => {
+        impl<const VALUE: $t> Const<VALUE> {
+            /// The shift amount represented by this constant.
+            /// Evaluating it fails to compile if the value is negative or not less than the bit width of the type.
+            #[doc(hidden)]
+            pub const SHIFT_AMOUNT: u32 = {
+                assert!((VALUE as u128) < <$t>::BITS as u128, "attempt to shift with overflow");
+                VALUE as u32
+            };
+
+            /// The bitwise negation of this constant.
+            #[doc(hidden)]
+            pub const NOT: $t = !VALUE;
+        }
+
+        /// Results of operations on two constants.
+        /// Evaluating one of them fails to compile if the operation overflows.
+        #[doc(hidden)]
+        pub struct Operands<const VALUE1: $t, const VALUE2: $t>;
+
+        impl<const VALUE1: $t, const VALUE2: $t> Operands<VALUE1, VALUE2> {
+            pub const ADD: $t = match VALUE1.checked_add(VALUE2) {
+                Some(value) => value,
+                None => panic!("attempt to add with overflow"),
+            };
+            pub const SUB: $t = match VALUE1.checked_sub(VALUE2) {
+                Some(value) => value,
+                None => panic!("attempt to subtract with overflow"),
+            };
+            pub const MUL: $t = match VALUE1.checked_mul(VALUE2) {
+                Some(value) => value,
+                None => panic!("attempt to multiply with overflow"),
+            };
+            pub const DIV: $t = match VALUE1.checked_div(VALUE2) {
+                Some(value) => value,
+                None => panic!("attempt to divide by zero or with overflow"),
+            };
+            pub const REM: $t = match VALUE1.checked_rem(VALUE2) {
+                Some(value) => value,
+                None => panic!("attempt to calculate the remainder with a divisor of zero or with overflow"),
+            };
+            pub const BITAND: $t = VALUE1 & VALUE2;
+            pub const BITOR: $t = VALUE1 | VALUE2;
+            pub const BITXOR: $t = VALUE1 ^ VALUE2;
+            pub const SHL: $t = VALUE1 << Const::<VALUE2>::SHIFT_AMOUNT;
+            pub const SHR: $t = VALUE1 >> Const::<VALUE2>::SHIFT_AMOUNT;
+            pub const MULTIPLE_OF: bool = if VALUE2 == 0 { VALUE1 == 0 } else { VALUE1.wrapping_rem(VALUE2) == 0 };
+        }
+
+        impl_min_binary_op!($t, Add, add, +, ADD);
+        impl_min_binary_op!($t, Sub, sub, -, SUB);
+        impl_min_binary_op!($t, Mul, mul, *, MUL);
+        impl_min_binary_op!($t, Div, div, /, DIV);
+        impl_min_binary_op!($t, Rem, rem, %, REM);
+        impl_min_binary_op!($t, BitAnd, bitand, &, BITAND);
+        impl_min_binary_op!($t, BitOr, bitor, |, BITOR);
+        impl_min_binary_op!($t, BitXor, bitxor, ^, BITXOR);
+        impl_min_shift_op!($t, Shl, shl, <<, SHL);
+        impl_min_shift_op!($t, Shr, shr, >>, SHR);
+        impl_min_checked_op!($t, CheckedAdd, checked_add, ADD);
+        impl_min_checked_op!($t, CheckedSub, checked_sub, SUB);
+        impl_min_checked_op!($t, CheckedMul, checked_mul, MUL);
+        impl_min_checked_op!($t, CheckedDiv, checked_div, DIV);
+        impl_min_checked_op!($t, CheckedRem, checked_rem, REM);
+        impl_min_safe_div!($kind, $t);
+        impl_min_wrapping_op!($t, WrappingAdd, wrapping_add);
+        impl_min_wrapping_op!($t, WrappingSub, wrapping_sub);
+        impl_min_wrapping_op!($t, WrappingMul, wrapping_mul);
+        impl_min_wrapping_op!($t, SaturatingAdd, saturating_add);
+        impl_min_wrapping_op!($t, SaturatingSub, saturating_sub);
+        impl_min_wrapping_op!($t, SaturatingMul, saturating_mul);
+        impl_min_unary_op!($t, Not, not, NOT);
+        impl_min_neg!($kind, $t);
+
+        #[cfg(test)]
+        mod test_min_ops {
+            use super::*;
+            #[test]
+            fn test_arithmetic() {
+                assert_eq!(Const::<{7 as $t}>::new() + Const::<{2 as $t}>::new(), 9 as $t);
+                assert_eq!(Const::<{7 as $t}>::new() + {2 as $t}, 9 as $t);
+                assert_eq!({7 as $t} + Const::<{2 as $t}>::new(), 9 as $t);
+
+                assert_eq!(Const::<{7 as $t}>::new() - Const::<{2 as $t}>::new(), 5 as $t);
+                assert_eq!(Const::<{7 as $t}>::new() - {2 as $t}, 5 as $t);
+                assert_eq!({7 as $t} - Const::<{2 as $t}>::new(), 5 as $t);
+
+                assert_eq!(Const::<{7 as $t}>::new() * Const::<{2 as $t}>::new(), 14 as $t);
+                assert_eq!(Const::<{7 as $t}>::new() * {2 as $t}, 14 as $t);
+                assert_eq!({7 as $t} * Const::<{2 as $t}>::new(), 14 as $t);
+
+                assert_eq!(Const::<{7 as $t}>::new() / Const::<{2 as $t}>::new(), 3 as $t);
+                assert_eq!(Const::<{7 as $t}>::new() / {2 as $t}, 3 as $t);
+                assert_eq!({7 as $t} / Const::<{2 as $t}>::new(), 3 as $t);
+
+                assert_eq!(Const::<{7 as $t}>::new() % Const::<{2 as $t}>::new(), 1 as $t);
+                assert_eq!(Const::<{7 as $t}>::new() % {2 as $t}, 1 as $t);
+                assert_eq!({7 as $t} % Const::<{2 as $t}>::new(), 1 as $t);
+
+                // let _ = Const::<{<$t>::MAX}>::new() + Const::<{1 as $t}>::new(); // <-Compile Error!
+                // let _ = Const::<{1 as $t}>::new() / Const::<{0 as $t}>::new(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_bitwise() {
+                assert_eq!(Const::<{6 as $t}>::new() & Const::<{3 as $t}>::new(), 2 as $t);
+                assert_eq!(Const::<{6 as $t}>::new() & {3 as $t}, 2 as $t);
+                assert_eq!({6 as $t} & Const::<{3 as $t}>::new(), 2 as $t);
+
+                assert_eq!(Const::<{6 as $t}>::new() | Const::<{3 as $t}>::new(), 7 as $t);
+                assert_eq!(Const::<{6 as $t}>::new() | {3 as $t}, 7 as $t);
+                assert_eq!({6 as $t} | Const::<{3 as $t}>::new(), 7 as $t);
+
+                assert_eq!(Const::<{6 as $t}>::new() ^ Const::<{3 as $t}>::new(), 5 as $t);
+                assert_eq!(Const::<{6 as $t}>::new() ^ {3 as $t}, 5 as $t);
+                assert_eq!({6 as $t} ^ Const::<{3 as $t}>::new(), 5 as $t);
+
+                assert_eq!(!Const::<{0 as $t}>::new(), !(0 as $t));
+            }
+
+            #[test]
+            fn test_shift() {
+                assert_eq!(Const::<{6 as $t}>::new() << Const::<{2 as $t}>::new(), 24 as $t);
+                assert_eq!(Const::<{6 as $t}>::new() << {2 as $t}, 24 as $t);
+                assert_eq!({6 as $t} << Const::<{2 as $t}>::new(), 24 as $t);
+
+                assert_eq!(Const::<{6 as $t}>::new() >> Const::<{2 as $t}>::new(), 1 as $t);
+                assert_eq!(Const::<{6 as $t}>::new() >> {2 as $t}, 1 as $t);
+                assert_eq!({6 as $t} >> Const::<{2 as $t}>::new(), 1 as $t);
+
+                assert_eq!({1 as $t} << Const::<{<$t>::BITS as $t - 1}>::new(), {1 as $t}.rotate_right(1));
+                // let _ = {1 as $t} << Const::<{<$t>::BITS as $t}>::new(); // <-Compile Error!
+                // let _ = Const::<{1 as $t}>::new() >> Const::<{<$t>::BITS as $t}>::new(); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_checked() {
+                use crate::{CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, CheckedRem};
+                assert_eq!(CheckedAdd::checked_add(Const::<{7 as $t}>::new(), Const::<{2 as $t}>::new()).map(|value| crate::MayBeConstAT::value(&value)), Some(9 as $t));
+                assert_eq!(CheckedAdd::checked_add(Const::<{7 as $t}>::new(), 2 as $t), Some(9 as $t));
+                assert_eq!(CheckedAdd::checked_add(7 as $t, Const::<{2 as $t}>::new()), Some(9 as $t));
+                assert_eq!(CheckedAdd::checked_add(7 as $t, 2 as $t), Some(9 as $t));
+                assert_eq!(CheckedAdd::checked_add(Const::<{<$t>::MAX}>::new(), 1 as $t), None);
+                assert_eq!(CheckedAdd::checked_add(<$t>::MAX, Const::<{1 as $t}>::new()), None);
+                // CheckedAdd::checked_add(Const::<{<$t>::MAX}>::new(), Const::<{1 as $t}>::new()); // <-Compile Error!
+
+                assert_eq!(CheckedSub::checked_sub(Const::<{7 as $t}>::new(), Const::<{2 as $t}>::new()).map(|value| crate::MayBeConstAT::value(&value)), Some(5 as $t));
+                assert_eq!(CheckedSub::checked_sub(Const::<{<$t>::MIN}>::new(), 1 as $t), None);
+                assert_eq!(CheckedSub::checked_sub(<$t>::MIN, Const::<{1 as $t}>::new()), None);
+                // CheckedSub::checked_sub(Const::<{<$t>::MIN}>::new(), Const::<{1 as $t}>::new()); // <-Compile Error!
+
+                assert_eq!(CheckedMul::checked_mul(Const::<{7 as $t}>::new(), Const::<{2 as $t}>::new()).map(|value| crate::MayBeConstAT::value(&value)), Some(14 as $t));
+                assert_eq!(CheckedMul::checked_mul(Const::<{<$t>::MAX}>::new(), 2 as $t), None);
+                assert_eq!(CheckedMul::checked_mul(<$t>::MAX, Const::<{2 as $t}>::new()), None);
+                // CheckedMul::checked_mul(Const::<{<$t>::MAX}>::new(), Const::<{2 as $t}>::new()); // <-Compile Error!
+
+                assert_eq!(CheckedDiv::checked_div(Const::<{7 as $t}>::new(), Const::<{2 as $t}>::new()).map(|value| crate::MayBeConstAT::value(&value)), Some(3 as $t));
+                assert_eq!(CheckedDiv::checked_div(Const::<{7 as $t}>::new(), 0 as $t), None);
+                assert_eq!(CheckedDiv::checked_div(7 as $t, Const::<{0 as $t}>::new()), None);
+                // CheckedDiv::checked_div(Const::<{7 as $t}>::new(), Const::<{0 as $t}>::new()); // <-Compile Error!
+
+                assert_eq!(CheckedRem::checked_rem(Const::<{7 as $t}>::new(), Const::<{2 as $t}>::new()).map(|value| crate::MayBeConstAT::value(&value)), Some(1 as $t));
+                assert_eq!(CheckedRem::checked_rem(Const::<{7 as $t}>::new(), 0 as $t), None);
+                assert_eq!(CheckedRem::checked_rem(7 as $t, Const::<{0 as $t}>::new()), None);
+                // CheckedRem::checked_rem(Const::<{7 as $t}>::new(), Const::<{0 as $t}>::new()); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_safe_div() {
+                use crate::{SafeDiv, SafeRem};
+                assert_eq!(SafeDiv::safe_div(Const::<{100 as $t}>::new(), Const::<{8 as $t}>::new()), 12 as $t);
+                assert_eq!(SafeDiv::safe_div(100 as $t, Const::<{8 as $t}>::new()), 12 as $t);
+                assert_eq!(SafeDiv::safe_div(100 as $t, Const::<{7 as $t}>::new()), 14 as $t);
+                assert_eq!(SafeDiv::safe_div(<$t>::MAX, Const::<{1 as $t}>::new()), <$t>::MAX);
+                assert_eq!(SafeDiv::safe_div(Const::<{100 as $t}>::new(), 8 as $t), Some(12 as $t));
+                assert_eq!(SafeDiv::safe_div(Const::<{100 as $t}>::new(), 0 as $t), None);
+                assert_eq!(SafeDiv::safe_div(100 as $t, 8 as $t), Some(12 as $t));
+                assert_eq!(SafeDiv::safe_div(100 as $t, 0 as $t), None);
+                // SafeDiv::safe_div(100 as $t, Const::<{0 as $t}>::new()); // <-Compile Error!
+
+                assert_eq!(SafeRem::safe_rem(Const::<{100 as $t}>::new(), Const::<{8 as $t}>::new()), 4 as $t);
+                assert_eq!(SafeRem::safe_rem(100 as $t, Const::<{8 as $t}>::new()), 4 as $t);
+                assert_eq!(SafeRem::safe_rem(100 as $t, Const::<{7 as $t}>::new()), 2 as $t);
+                assert_eq!(SafeRem::safe_rem(<$t>::MAX, Const::<{1 as $t}>::new()), 0 as $t);
+                assert_eq!(SafeRem::safe_rem(Const::<{100 as $t}>::new(), 8 as $t), Some(4 as $t));
+                assert_eq!(SafeRem::safe_rem(100 as $t, 0 as $t), None);
+                // SafeRem::safe_rem(100 as $t, Const::<{0 as $t}>::new()); // <-Compile Error!
+            }
+
+            #[test]
+            fn test_wrapping() {
+                use crate::{WrappingAdd, WrappingSub, WrappingMul};
+                assert_eq!(WrappingAdd::wrapping_add(Const::<{<$t>::MAX}>::new(), Const::<{1 as $t}>::new()), <$t>::MIN);
+                assert_eq!(WrappingAdd::wrapping_add(Const::<{<$t>::MAX}>::new(), 1 as $t), <$t>::MIN);
+                assert_eq!(WrappingAdd::wrapping_add(<$t>::MAX, Const::<{1 as $t}>::new()), <$t>::MIN);
+                assert_eq!(WrappingAdd::wrapping_add(<$t>::MAX, 1 as $t), <$t>::MIN);
+
+                assert_eq!(WrappingSub::wrapping_sub(Const::<{<$t>::MIN}>::new(), Const::<{1 as $t}>::new()), <$t>::MAX);
+                assert_eq!(WrappingSub::wrapping_sub(Const::<{<$t>::MIN}>::new(), 1 as $t), <$t>::MAX);
+                assert_eq!(WrappingSub::wrapping_sub(<$t>::MIN, Const::<{1 as $t}>::new()), <$t>::MAX);
+
+                assert_eq!(WrappingMul::wrapping_mul(Const::<{<$t>::MAX}>::new(), Const::<{2 as $t}>::new()), <$t>::MAX.wrapping_mul(2));
+                assert_eq!(WrappingMul::wrapping_mul(Const::<{<$t>::MAX}>::new(), 2 as $t), <$t>::MAX.wrapping_mul(2));
+                assert_eq!(WrappingMul::wrapping_mul(<$t>::MAX, Const::<{2 as $t}>::new()), <$t>::MAX.wrapping_mul(2));
+            }
+
+            #[test]
+            fn test_saturating() {
+                use crate::{SaturatingAdd, SaturatingSub, SaturatingMul};
+                assert_eq!(SaturatingAdd::saturating_add(Const::<{<$t>::MAX}>::new(), Const::<{1 as $t}>::new()), <$t>::MAX);
+                assert_eq!(SaturatingAdd::saturating_add(Const::<{<$t>::MAX}>::new(), 1 as $t), <$t>::MAX);
+                assert_eq!(SaturatingAdd::saturating_add(<$t>::MAX, Const::<{1 as $t}>::new()), <$t>::MAX);
+                assert_eq!(SaturatingAdd::saturating_add(<$t>::MAX, 1 as $t), <$t>::MAX);
+
+                assert_eq!(SaturatingSub::saturating_sub(Const::<{<$t>::MIN}>::new(), Const::<{1 as $t}>::new()), <$t>::MIN);
+                assert_eq!(SaturatingSub::saturating_sub(Const::<{<$t>::MIN}>::new(), 1 as $t), <$t>::MIN);
+                assert_eq!(SaturatingSub::saturating_sub(<$t>::MIN, Const::<{1 as $t}>::new()), <$t>::MIN);
+
+                assert_eq!(SaturatingMul::saturating_mul(Const::<{<$t>::MAX}>::new(), Const::<{2 as $t}>::new()), <$t>::MAX);
+                assert_eq!(SaturatingMul::saturating_mul(Const::<{<$t>::MAX}>::new(), 2 as $t), <$t>::MAX);
+                assert_eq!(SaturatingMul::saturating_mul(<$t>::MAX, Const::<{2 as $t}>::new()), <$t>::MAX);
+            }
+
+            #[test]
+            fn test_arithmetic_generic() {
+                fn add<A: crate::MayBeConst<$t> + core::ops::Add<B>, B: crate::MayBeConst<$t>>(a: A, b: B) -> A::Output {
+                    a + b
+                }
+                assert_eq!(add(Const::<{1 as $t}>::new(), Const::<{2 as $t}>::new()), 3 as $t);
+                assert_eq!(add(Const::<{1 as $t}>::new(), 2 as $t), 3 as $t);
+                assert_eq!(add(1 as $t, Const::<{2 as $t}>::new()), 3 as $t);
+                assert_eq!(add(1 as $t, 2 as $t), 3 as $t);
+            }
+
+            #[test]
+            fn test_arithmetic_runtime_output() {
+                fn output_is_const<A: core::ops::Add<B>, B>() -> bool where A::Output: crate::MayBeConstAT {
+                    <A::Output as crate::MayBeConstAT>::IS_CONST
+                }
+                assert!(!output_is_const::<Const<{1 as $t}>, $t>());
+                assert!(!output_is_const::<$t, Const<{1 as $t}>>());
+                assert!(!output_is_const::<$t, $t>());
+            }
+        }
+    };
+}
+
+macro_rules! impl_min_value_equals {
+    ($from:tt, $to:tt) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        #[allow(unused_comparisons)]
+        impl<const VALUE1: $from, const VALUE2: $to> crate::ValueEquals<crate::$to::Const<VALUE2>> for crate::$from::Const<VALUE1> {
+            type ConstSide = Self;
+            const ASSERT: () = assert!(any_int!(VALUE1).eq(any_int!(VALUE2)), "constraint `ValueEquals` is not satisfied");
+            #[inline(always)]
+            fn get_value_const_side(&self, _: &crate::$to::Const<VALUE2>) -> Option<Self> {
+                let () = <Self as crate::ValueEquals<crate::$to::Const<VALUE2>>>::ASSERT;
+                Some(*self)
+            }
+        }
+        #[allow(unused_comparisons)]
+        impl<const VALUE: $from> crate::ValueEquals<$to> for crate::$from::Const<VALUE> {
+            type ConstSide = Self;
+            #[inline(always)]
+            fn get_value_const_side(&self, rhs: &$to) -> Option<Self> {
+                if any_int!(VALUE).eq(any_int!(*rhs)) {
+                    Some(*self)
+                } else {
+                    None
+                }
+            }
+        }
+        #[allow(unused_comparisons)]
+        impl<const VALUE: $to> crate::ValueEquals<crate::$to::Const<VALUE>> for $from {
+            type ConstSide = crate::$to::Const<VALUE>;
+            #[inline(always)]
+            fn get_value_const_side(&self, rhs: &crate::$to::Const<VALUE>) -> Option<Self::ConstSide> {
+                if any_int!(*self).eq(any_int!(VALUE)) {
+                    Some(*rhs)
+                } else {
+                    None
+                }
+            }
+        }
+        #[allow(unused_comparisons)]
+        impl crate::ValueEquals<$to> for $from {
+            type ConstSide = $from;
+            #[inline(always)]
+            fn get_value_const_side(&self, rhs: &$to) -> Option<$from> {
+                if any_int!(*self).eq(any_int!(*rhs)) {
+                    Some(*self)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Compile-time comparison of two constants of different types.
+#[doc(hidden)]
+pub trait ConstOrd<Rhs> {
+    const ORDERING: core::cmp::Ordering;
+}
+
+macro_rules! impl_min_compare {
+    ($a:tt, $b:tt) => {
+        impl<const VALUE1: $a, const VALUE2: $b> crate::ConstOrd<crate::$b::Const<VALUE2>> for crate::$a::Const<VALUE1> {
+            const ORDERING: core::cmp::Ordering = crate::$a::Const::<VALUE1>::ANY_INT.cmp(crate::$b::Const::<VALUE2>::ANY_INT);
+        }
+
+        impl<const VALUE1: $a, const VALUE2: $b> core::cmp::PartialEq<crate::$b::Const<VALUE2>> for crate::$a::Const<VALUE1> {
+            fn eq(&self, _: &crate::$b::Const<VALUE2>) -> bool {
+                matches!(<Self as crate::ConstOrd<crate::$b::Const<VALUE2>>>::ORDERING, core::cmp::Ordering::Equal)
+            }
+        }
+        impl<const VALUE1: $a, const VALUE2: $b> core::cmp::PartialOrd<crate::$b::Const<VALUE2>> for crate::$a::Const<VALUE1> {
+            fn partial_cmp(&self, _: &crate::$b::Const<VALUE2>) -> Option<core::cmp::Ordering> {
+                Some(<Self as crate::ConstOrd<crate::$b::Const<VALUE2>>>::ORDERING)
+            }
+        }
+
+        impl<const VALUE1: $a, const VALUE2: $b> crate::ValueOrd<crate::$b::Const<VALUE2>> for crate::$a::Const<VALUE1> {
+            #[inline(always)]
+            fn value_cmp(&self, _: &crate::$b::Const<VALUE2>) -> core::cmp::Ordering {
+                <Self as crate::ConstOrd<crate::$b::Const<VALUE2>>>::ORDERING
+            }
+        }
+        #[allow(unused_comparisons)]
+        impl<const VALUE: $a> crate::ValueOrd<$b> for crate::$a::Const<VALUE> {
+            #[inline(always)]
+            fn value_cmp(&self, rhs: &$b) -> core::cmp::Ordering {
+                Self::ANY_INT.cmp(any_int!(*rhs))
+            }
+        }
+        #[allow(unused_comparisons)]
+        impl<const VALUE: $b> crate::ValueOrd<crate::$b::Const<VALUE>> for $a {
+            #[inline(always)]
+            fn value_cmp(&self, _: &crate::$b::Const<VALUE>) -> core::cmp::Ordering {
+                any_int!(*self).cmp(crate::$b::Const::<VALUE>::ANY_INT)
+            }
+        }
+        #[allow(unused_comparisons)]
+        impl crate::ValueOrd<$b> for $a {
+            #[inline(always)]
+            fn value_cmp(&self, rhs: &$b) -> core::cmp::Ordering {
+                any_int!(*self).cmp(any_int!(*rhs))
+            }
+        }
+
+        impl_min_compare!(@ordering $a, $b, Lt, core::cmp::Ordering::Less);
+        impl_min_compare!(@ordering $a, $b, Gt, core::cmp::Ordering::Greater);
+        impl_min_compare!(@ordering $a, $b, Leq, core::cmp::Ordering::Less | core::cmp::Ordering::Equal);
+        impl_min_compare!(@ordering $a, $b, Geq, core::cmp::Ordering::Greater | core::cmp::Ordering::Equal);
+    };
+    (@ordering $a:tt, $b:tt, $trait:ident, $($satisfied:pat)|+) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE1: $a, const VALUE2: $b> crate::$trait<crate::$b::Const<VALUE2>> for crate::$a::Const<VALUE1> {
+            const ASSERT: () = assert!(
+                matches!(<Self as crate::ConstOrd<crate::$b::Const<VALUE2>>>::ORDERING, $($satisfied)|+),
+                concat!("constraint `", stringify!($trait), "` is not satisfied")
+            );
+            impl_ordering_check!($trait, crate::$b::Const<VALUE2>, const);
+        }
+    };
+}
+
+macro_rules! impl_min_widen {
+    ($from:tt, $to:tt) => {
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $from> crate::Widen<$to> for crate::$from::Const<VALUE> {
+            type Output = $to;
+            #[inline(always)]
+            fn widen(self) -> $to {
+                <$to>::from(VALUE)
+            }
+        }
+    }
+}
+
+macro_rules! impl_min_cast {
+    ($from:tt, $to:tt) => {
+        #[allow(unused_comparisons)]
+        impl<const VALUE: $from> crate::ConstCast<$to> for crate::$from::Const<VALUE> {
+            const TRY_CAST: $to = {
+                let value = VALUE as $to;
+                assert!(value as $from == VALUE && (value < 0) == (VALUE < 0), "out of range integral type conversion attempted");
+                value
+            };
+        }
+
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $from> crate::TryCastTo<$to> for crate::$from::Const<VALUE> {
+            type Output = $to;
+            #[inline(always)]
+            fn try_cast_to(self) -> $to {
+                <Self as crate::ConstCast<$to>>::TRY_CAST
+            }
+        }
+
+        #[rustversion::attr(nightly, cfg(not(feature = "incomplete")))]
+        impl<const VALUE: $from> crate::AsCast<$to> for crate::$from::Const<VALUE> {
+            type Output = $to;
+            #[inline(always)]
+            fn as_cast(self) -> $to {
+                VALUE as $to
+            }
+        }
     }
 }
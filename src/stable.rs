@@ -25,6 +25,43 @@ pub trait MayBeConstAT: Sized + Clone + Copy + Default + core::fmt::Debug + core
 
 impl<T: MayBeConstAT> MayBeConst<T::Type> for T {}
 
+/// A trait for the primitive integer types.
+/// It is the [MayBeConstAT::Type] of every `MayBeConstInt`.
+pub trait PrimInt: Copy + Ord + core::hash::Hash + Default + core::fmt::Debug + core::fmt::Display
+    + core::ops::Add<Output=Self> + core::ops::Sub<Output=Self> + core::ops::Mul<Output=Self>
+    + core::ops::Div<Output=Self> + core::ops::Rem<Output=Self>
+    + core::ops::BitAnd<Output=Self> + core::ops::BitOr<Output=Self> + core::ops::BitXor<Output=Self> + core::ops::Not<Output=Self>
+    + core::ops::Shl<u32, Output=Self> + core::ops::Shr<u32, Output=Self> {
+    /// The size of the type in bits.
+    const BITS: u32;
+    /// The smallest value of the type.
+    const MIN: Self;
+    /// The largest value of the type.
+    const MAX: Self;
+    /// Whether the type is signed.
+    const SIGNED: bool;
+    /// Zero.
+    const ZERO: Self;
+    /// One.
+    const ONE: Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+    fn pow(self, exp: u32) -> Self;
+    fn count_ones(self) -> u32;
+    fn count_zeros(self) -> u32;
+    fn leading_zeros(self) -> u32;
+    fn trailing_zeros(self) -> u32;
+}
+
 /// A trait for putting equality constraints on constants.
 /// It will be implemented if the constants are equal.
 ///
@@ -81,6 +118,742 @@ pub trait Equals<T: MayBeConstAT>: MayBeConstAT<Type=T::Type> {
     fn get_const_side(&self, rhs: &T) -> Option<Self::ConstSide>;
 }
 
+/// A trait for merging two values which may or may not be constants.
+/// Unlike [Equals], it is implemented for every pair of values of the same type, so it never fails to compile.
+///
+/// [Unify::Output] is the constant if both sides are the same constant, and the primitive type otherwise.
+/// Without the `incomplete` feature, a pair of integer or char constants always unifies to the primitive type,
+/// because the output type can not depend on whether the constants are equal.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u8")] #[rustversion::since(1.51)] fn test() {
+/// use partial_const::{ConstU8, MayBeConstAT, Unify};
+///
+/// fn merge<A: partial_const::MayBeConst<u8>, B: partial_const::MayBeConst<u8>>(a: A, b: B) -> Option<u8>
+///     where A: Unify<B> {
+///     a.unify(&b).map(|value| value.value())
+/// }
+///
+/// assert_eq!(merge(ConstU8::<1>::new(), 1u8), Some(1));
+/// assert_eq!(merge(ConstU8::<1>::new(), 2u8), None);
+/// assert_eq!(merge::<ConstU8<1>, ConstU8<2>>(ConstU8::<1>::new(), ConstU8::<2>::new()), None);
+/// assert_eq!(merge(1u8, 1u8), Some(1));
+/// # }
+/// # #[cfg(not(feature = "u8"))] fn test(){}
+/// # #[cfg(feature = "u8")] #[rustversion::not(since(1.51))] fn test(){}
+/// # test();
+/// ```
+pub trait Unify<B: MayBeConstAT>: MayBeConstAT<Type=B::Type> {
+    /// The least type which can hold both sides.
+    type Output: MayBeConst<B::Type>;
+    /// Return the merged value if two values are equal.
+    fn unify(&self, rhs: &B) -> Option<Self::Output>;
+}
+
+/// A trait for putting less-than constraints on constants.
+///
+/// With the `incomplete` feature, `Const<A>` implements it only for a constant `Rhs` greater than `A`.
+/// Without it, `Const<A>: Lt<Const<B>>` holds for every `A` and `B`,
+/// and `A >= B` is rejected only when [Lt::check] or [Lt::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u8")] #[rustversion::since(1.57)] fn test() {
+/// fn lt<A: partial_const::MayBeConst<u8>, B: partial_const::MayBeConst<u8>>(a: A, b: B) -> bool
+///     where A: partial_const::Lt<B> {
+///     a.check(&b).is_ok()
+/// }
+///
+/// assert!(lt(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<2>::new()));
+/// assert!(lt(partial_const::ConstU8::<1>::new(), 2));
+/// assert!(!lt(partial_const::ConstU8::<1>::new(), 1));
+/// // assert!(lt(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<1>::new())); <- Compile Error
+/// // assert!(lt(partial_const::ConstU8::<2>::new(), partial_const::ConstU8::<1>::new())); <- Compile Error
+/// # }
+/// # #[cfg(not(feature = "u8"))] fn test(){}
+/// # #[cfg(feature = "u8")] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait Lt<Rhs: MayBeConstAT>: MayBeConstAT {
+    /// Evaluating this fails to compile if both sides are constants and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the constraint at runtime.
+    /// It always succeeds if both sides are constants, because a pair of constants which violates it fails to compile.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "u8")] #[rustversion::since(1.57)] fn test() {
+    /// use partial_const::{ConstU8, Lt};
+    ///
+    /// let proof = ConstU8::<1>::new().check(&ConstU8::<2>::new()).unwrap();
+    /// assert!(proof.lhs_is_const() && proof.rhs_is_const());
+    /// let proof = ConstU8::<1>::new().check(&2).unwrap();
+    /// assert!(proof.lhs_is_const() && !proof.rhs_is_const());
+    /// let error = 2u8.check(&ConstU8::<1>::new()).unwrap_err();
+    /// assert_eq!(error.to_string(), "2 < 1 is not satisfied");
+    /// # }
+    /// # #[cfg(not(feature = "u8"))] fn test(){}
+    /// # #[cfg(feature = "u8")] #[rustversion::not(since(1.57))] fn test(){}
+    /// # test();
+    /// ```
+    fn check(&self, rhs: &Rhs) -> Result<OrderingProof, OrderingViolation<Self::Type, Rhs::Type>>;
+}
+
+/// A trait for putting greater-than constraints on constants.
+///
+/// With the `incomplete` feature, `Const<A>` implements it only for a constant `Rhs` less than `A`.
+/// Without it, `Const<A>: Gt<Const<B>>` holds for every `A` and `B`,
+/// and `A <= B` is rejected only when [Gt::check] or [Gt::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u8")] #[rustversion::since(1.57)] fn test() {
+/// fn gt<A: partial_const::MayBeConst<u8>, B: partial_const::MayBeConst<u8>>(a: A, b: B) -> bool
+///     where A: partial_const::Gt<B> {
+///     a.check(&b).is_ok()
+/// }
+///
+/// assert!(gt(partial_const::ConstU8::<2>::new(), partial_const::ConstU8::<1>::new()));
+/// assert!(gt(partial_const::ConstU8::<2>::new(), 1));
+/// assert!(!gt(partial_const::ConstU8::<1>::new(), 1));
+/// // assert!(gt(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<1>::new())); <- Compile Error
+/// // assert!(gt(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<2>::new())); <- Compile Error
+/// # }
+/// # #[cfg(not(feature = "u8"))] fn test(){}
+/// # #[cfg(feature = "u8")] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait Gt<Rhs: MayBeConstAT>: MayBeConstAT {
+    /// Evaluating this fails to compile if both sides are constants and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the constraint at runtime.
+    /// It always succeeds if both sides are constants, because a pair of constants which violates it fails to compile.
+    fn check(&self, rhs: &Rhs) -> Result<OrderingProof, OrderingViolation<Self::Type, Rhs::Type>>;
+}
+
+/// A trait for putting less-than-or-equal constraints on constants.
+///
+/// With the `incomplete` feature, `Const<A>` implements it only for a constant `Rhs` greater than or equal to `A`.
+/// Without it, `Const<A>: Leq<Const<B>>` holds for every `A` and `B`,
+/// and `A > B` is rejected only when [Leq::check] or [Leq::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u8")] #[rustversion::since(1.57)] fn test() {
+/// fn leq<A: partial_const::MayBeConst<u8>, B: partial_const::MayBeConst<u8>>(a: A, b: B) -> bool
+///     where A: partial_const::Leq<B> {
+///     a.check(&b).is_ok()
+/// }
+///
+/// assert!(leq(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<2>::new()));
+/// assert!(leq(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<1>::new()));
+/// assert!(leq(partial_const::ConstU8::<1>::new(), 1));
+/// assert!(!leq(partial_const::ConstU8::<2>::new(), 1));
+/// // assert!(leq(partial_const::ConstU8::<2>::new(), partial_const::ConstU8::<1>::new())); <- Compile Error
+/// # }
+/// # #[cfg(not(feature = "u8"))] fn test(){}
+/// # #[cfg(feature = "u8")] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait Leq<Rhs: MayBeConstAT>: MayBeConstAT {
+    /// Evaluating this fails to compile if both sides are constants and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the constraint at runtime.
+    /// It always succeeds if both sides are constants, because a pair of constants which violates it fails to compile.
+    fn check(&self, rhs: &Rhs) -> Result<OrderingProof, OrderingViolation<Self::Type, Rhs::Type>>;
+}
+
+/// A trait for putting greater-than-or-equal constraints on constants.
+///
+/// With the `incomplete` feature, `Const<A>` implements it only for a constant `Rhs` less than or equal to `A`.
+/// Without it, `Const<A>: Geq<Const<B>>` holds for every `A` and `B`,
+/// and `A < B` is rejected only when [Geq::check] or [Geq::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u8")] #[rustversion::since(1.57)] fn test() {
+/// fn geq<A: partial_const::MayBeConst<u8>, B: partial_const::MayBeConst<u8>>(a: A, b: B) -> bool
+///     where A: partial_const::Geq<B> {
+///     a.check(&b).is_ok()
+/// }
+///
+/// assert!(geq(partial_const::ConstU8::<2>::new(), partial_const::ConstU8::<1>::new()));
+/// assert!(geq(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<1>::new()));
+/// assert!(geq(partial_const::ConstU8::<1>::new(), 1));
+/// assert!(!geq(partial_const::ConstU8::<1>::new(), 2));
+/// // assert!(geq(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<2>::new())); //<- Compile Error
+/// # }
+/// # #[cfg(not(feature = "u8"))] fn test(){}
+/// # #[cfg(feature = "u8")] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait Geq<Rhs: MayBeConstAT>: MayBeConstAT {
+    /// Evaluating this fails to compile if both sides are constants and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the constraint at runtime.
+    /// It always succeeds if both sides are constants, because a pair of constants which violates it fails to compile.
+    fn check(&self, rhs: &Rhs) -> Result<OrderingProof, OrderingViolation<Self::Type, Rhs::Type>>;
+}
+
+/// A trait for putting inequality constraints on constants.
+/// It is the opposite of [Equals].
+///
+/// With the `incomplete` feature, two constants implement it only if they differ.
+/// Without it, `Const<A>: NotEquals<Const<B>>` also holds for `A == B`,
+/// and equal constants are rejected only when [NotEquals::check_distinct] or [NotEquals::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u8")] #[rustversion::since(1.57)] fn test() {
+/// fn distinct<A: partial_const::MayBeConst<u8>, B: partial_const::MayBeConst<u8>>(a: A, b: B) -> bool
+///     where A: partial_const::NotEquals<B> {
+///     a.check_distinct(&b).is_ok()
+/// }
+///
+/// assert!(distinct(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<2>::new()));
+/// assert!(distinct(partial_const::ConstU8::<1>::new(), 2));
+/// assert!(!distinct(partial_const::ConstU8::<1>::new(), 1));
+/// // assert!(distinct(partial_const::ConstU8::<1>::new(), partial_const::ConstU8::<1>::new())); <- Compile Error
+/// # }
+/// # #[cfg(not(feature = "u8"))] fn test(){}
+/// # #[cfg(feature = "u8")] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait NotEquals<Rhs: MayBeConstAT>: MayBeConstAT<Type=Rhs::Type> {
+    /// Evaluating this fails to compile if both sides are constants and they are equal.
+    const ASSERT: () = ();
+    /// Check that two values are distinct at runtime.
+    /// It always succeeds if both sides are constants, because two equal constants fail to compile.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "u8")] #[rustversion::since(1.57)] fn test() {
+    /// use partial_const::{ConstU8, NotEquals};
+    ///
+    /// assert!(ConstU8::<1>::new().check_distinct(&ConstU8::<2>::new()).is_ok());
+    /// assert!(ConstU8::<1>::new().check_distinct(&2).is_ok());
+    /// let error = 1u8.check_distinct(&ConstU8::<1>::new()).unwrap_err();
+    /// assert_eq!(error.to_string(), "1 != 1 is not satisfied");
+    /// # }
+    /// # #[cfg(not(feature = "u8"))] fn test(){}
+    /// # #[cfg(feature = "u8")] #[rustversion::not(since(1.57))] fn test(){}
+    /// # test();
+    /// ```
+    fn check_distinct(&self, rhs: &Rhs) -> Result<OrderingProof, OrderingViolation<Self::Type, Rhs::Type>>;
+}
+
+/// A proof that a comparison constraint is satisfied, returned by [Lt::check], [Gt::check], [Leq::check], [Geq::check] and [NotEquals::check_distinct].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrderingProof {
+    lhs_is_const: bool,
+    rhs_is_const: bool,
+}
+
+impl OrderingProof {
+    /// Whether the left hand side of the constraint is a constant.
+    pub fn lhs_is_const(&self) -> bool {
+        self.lhs_is_const
+    }
+
+    /// Whether the right hand side of the constraint is a constant.
+    pub fn rhs_is_const(&self) -> bool {
+        self.rhs_is_const
+    }
+}
+
+/// The error type returned when a comparison constraint is not satisfied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderingViolation<L, R> {
+    lhs: L,
+    rhs: R,
+    operator: &'static str,
+}
+
+impl<L, R> OrderingViolation<L, R> {
+    /// The left hand side of the constraint.
+    pub fn lhs(&self) -> &L {
+        &self.lhs
+    }
+
+    /// The right hand side of the constraint.
+    pub fn rhs(&self) -> &R {
+        &self.rhs
+    }
+}
+
+impl<L: core::fmt::Display, R: core::fmt::Display> core::fmt::Display for OrderingViolation<L, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} {} {} is not satisfied", self.lhs, self.operator, self.rhs)
+    }
+}
+
+#[inline(always)]
+fn check_ordering<L: MayBeConstAT, R: MayBeConstAT>(satisfied: bool, lhs: &L, rhs: &R, operator: &'static str) -> Result<OrderingProof, OrderingViolation<L::Type, R::Type>> {
+    if satisfied {
+        Ok(OrderingProof { lhs_is_const: L::IS_CONST, rhs_is_const: R::IS_CONST })
+    } else {
+        Err(OrderingViolation { lhs: lhs.value(), rhs: rhs.value(), operator })
+    }
+}
+
+macro_rules! impl_ordering_check {
+    ($trait:ident :: $method:ident, $rhs:ty, const) => {
+        #[inline(always)]
+        fn $method(&self, _: &$rhs) -> Result<crate::OrderingProof, crate::OrderingViolation<Self::Type, <$rhs as crate::MayBeConstAT>::Type>> {
+            let () = <Self as crate::$trait<$rhs>>::ASSERT;
+            Ok(crate::OrderingProof { lhs_is_const: true, rhs_is_const: true })
+        }
+    };
+    ($trait:ident :: $method:ident, $rhs:ty, $op:tt) => {
+        #[inline(always)]
+        fn $method(&self, rhs: &$rhs) -> Result<crate::OrderingProof, crate::OrderingViolation<Self::Type, <$rhs as crate::MayBeConstAT>::Type>> {
+            crate::check_ordering(crate::MayBeConstAT::value(self) $op crate::MayBeConstAT::value(rhs), self, rhs, stringify!($op))
+        }
+    };
+    ($trait:ident, $rhs:ty, $($rest:tt)*) => {
+        impl_ordering_check!($trait::check, $rhs, $($rest)*);
+    };
+}
+
+/// A trait for putting range constraints on constants.
+///
+/// With the `incomplete` feature, `Const<V>` implements it for constant bounds only if `V` is in the inclusive range from `Lo` to `Hi`.
+/// Without it, `Const<V>: InRange<Const<LO>, Const<HI>>` holds for every triple,
+/// and a value out of the range is rejected only when [InRange::check_in_range] or [InRange::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u8")] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{ConstU8, InRange, MayBeConst};
+///
+/// fn shift_amount<T: MayBeConst<u8>>(amount: T) -> u8
+///     where T: InRange<ConstU8<1>, ConstU8<64>> {
+///     amount.check_in_range(&ConstU8::<1>::new(), &ConstU8::<64>::new()).unwrap();
+///     amount.value()
+/// }
+///
+/// assert_eq!(shift_amount(ConstU8::<8>::new()), 8);
+/// assert_eq!(shift_amount(8u8), 8);
+/// // shift_amount(ConstU8::<65>::new()); <- Compile Error
+/// # }
+/// # #[cfg(not(feature = "u8"))] fn test(){}
+/// # #[cfg(feature = "u8")] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait InRange<Lo: MayBeConstAT, Hi: MayBeConstAT<Type=Lo::Type>>: MayBeConstAT<Type=Lo::Type> {
+    /// Evaluating this fails to compile if all values are constants and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check that the value is in the inclusive range from `lo` to `hi` at runtime.
+    /// It always succeeds if all values are constants, because a constant out of a constant range fails to compile.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "u8")] #[rustversion::since(1.57)] fn test() {
+    /// use partial_const::{ConstU8, InRange};
+    ///
+    /// assert!(ConstU8::<8>::new().check_in_range(&ConstU8::<1>::new(), &ConstU8::<64>::new()).is_ok());
+    /// assert!(8u8.check_in_range(&ConstU8::<1>::new(), &64).is_ok());
+    /// let error = 0u8.check_in_range(&ConstU8::<1>::new(), &ConstU8::<64>::new()).unwrap_err();
+    /// assert_eq!(error.to_string(), "0 is out of the range 1..=64");
+    /// # }
+    /// # #[cfg(not(feature = "u8"))] fn test(){}
+    /// # #[cfg(feature = "u8")] #[rustversion::not(since(1.57))] fn test(){}
+    /// # test();
+    /// ```
+    fn check_in_range(&self, lo: &Lo, hi: &Hi) -> Result<(), OutOfRange<Self::Type>>;
+}
+
+/// The error type returned when a range constraint is not satisfied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutOfRange<T> {
+    value: T,
+    lo: T,
+    hi: T,
+}
+
+impl<T> OutOfRange<T> {
+    /// The value which is out of range.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The lower bound of the range.
+    pub fn lo(&self) -> &T {
+        &self.lo
+    }
+
+    /// The upper bound of the range.
+    pub fn hi(&self) -> &T {
+        &self.hi
+    }
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for OutOfRange<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} is out of the range {}..={}", self.value, self.lo, self.hi)
+    }
+}
+
+#[inline(always)]
+fn check_range<T: PartialOrd>(value: T, lo: T, hi: T) -> Result<(), OutOfRange<T>> {
+    if lo <= value && value <= hi {
+        Ok(())
+    } else {
+        Err(OutOfRange { value, lo, hi })
+    }
+}
+
+macro_rules! impl_in_range_check {
+    ($lo:ty, $hi:ty, const) => {
+        #[inline(always)]
+        fn check_in_range(&self, _: &$lo, _: &$hi) -> Result<(), crate::OutOfRange<Self::Type>> {
+            let () = <Self as crate::InRange<$lo, $hi>>::ASSERT;
+            Ok(())
+        }
+    };
+    ($lo:ty, $hi:ty) => {
+        #[inline(always)]
+        fn check_in_range(&self, lo: &$lo, hi: &$hi) -> Result<(), crate::OutOfRange<Self::Type>> {
+            crate::check_range(crate::MayBeConstAT::value(self), crate::MayBeConstAT::value(lo), crate::MayBeConstAT::value(hi))
+        }
+    };
+}
+
+/// A trait for putting nonzero constraints on constants.
+///
+/// A constant which satisfies the constraint can be converted into the [core::num] nonzero types, such as [core::num::NonZeroU32], with [From].
+/// To accept either a constant or one of those types through one bound, use [MayBeConstNonZero].
+///
+/// With the `incomplete` feature, `Const<0>` does not implement it.
+/// Without it, every constant including `Const<0>` implements it,
+/// and zero is rejected only when [NonZero::check], [NonZero::ASSERT] or the conversion with [From] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u32")] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{ConstU32, MayBeConst, NonZero};
+///
+/// fn buffer_size<T: MayBeConst<u32> + NonZero>(size: T) -> u32 {
+///     size.check().unwrap();
+///     size.value()
+/// }
+///
+/// assert_eq!(buffer_size(ConstU32::<16>::new()), 16);
+/// assert_eq!(buffer_size(16u32), 16);
+/// // buffer_size(ConstU32::<0>::new()); <- Compile Error
+///
+/// let capacity: core::num::NonZeroU32 = ConstU32::<16>::new().into();
+/// assert_eq!(capacity.get(), 16);
+/// // let capacity: core::num::NonZeroU32 = ConstU32::<0>::new().into(); <- Compile Error
+/// # }
+/// # #[cfg(not(feature = "u32"))] fn test(){}
+/// # #[cfg(feature = "u32")] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait NonZero: MayBeConstAT {
+    /// Evaluating this fails to compile if the value is a constant and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the constraint at runtime.
+    /// It always succeeds if the value is a constant.
+    fn check(&self) -> Result<(), PropertyViolation<Self::Type>>;
+}
+
+/// A trait for handling nonzero constants and the nonzero types of [core::num], such as [core::num::NonZeroU32], in a common way.
+///
+/// The nonzero types of [core::num] have no [Default] value, so they do not implement [MayBeConst].
+/// This trait is implemented by them and by the constants which satisfy [NonZero].
+/// Like [NonZero], a zero constant is rejected only when a method is evaluated without the `incomplete` feature.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u32")] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{ConstU32, MayBeConstNonZero};
+///
+/// fn capacity<T: MayBeConstNonZero<NonZero=core::num::NonZeroU32>>(capacity: T) -> core::num::NonZeroU32 {
+///     capacity.nonzero()
+/// }
+///
+/// assert_eq!(capacity(ConstU32::<16>::new()).get(), 16);
+/// assert_eq!(capacity(core::num::NonZeroU32::new(16).unwrap()).get(), 16);
+/// // capacity(ConstU32::<0>::new()); <- Compile Error
+/// # }
+/// # #[cfg(not(feature = "u32"))] fn test(){}
+/// # #[cfg(feature = "u32")] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait MayBeConstNonZero: Sized + Clone + Copy + core::fmt::Debug + core::fmt::Display {
+    /// The primitive integer type.
+    type Int: PrimInt;
+    /// The nonzero type of [core::num] for [MayBeConstNonZero::Int].
+    type NonZero: Copy + Into<Self::Int>;
+    const IS_CONST: bool;
+    /// Return the value as the primitive integer type.
+    fn get(&self) -> Self::Int;
+    /// Return the value as the nonzero type.
+    fn nonzero(&self) -> Self::NonZero;
+}
+
+/// A trait for putting power-of-two constraints on constants.
+///
+/// With the `incomplete` feature, only constants which are powers of two implement it.
+/// Without it, every constant implements it, and any other constant is rejected only when [PowerOfTwo::check] or [PowerOfTwo::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "usize")] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{ConstUsize, PowerOfTwo};
+///
+/// assert!(ConstUsize::<8>::new().check().is_ok());
+/// assert!(8usize.check().is_ok());
+/// let error = 12usize.check().unwrap_err();
+/// assert_eq!(error.to_string(), "12 is not a power of two");
+/// // ConstUsize::<12>::new().check(); <- Compile Error
+/// # }
+/// # #[cfg(not(feature = "usize"))] fn test(){}
+/// # #[cfg(feature = "usize")] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait PowerOfTwo: MayBeConstAT {
+    /// Evaluating this fails to compile if the value is a constant and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the constraint at runtime.
+    /// It always succeeds if the value is a constant.
+    fn check(&self) -> Result<(), PropertyViolation<Self::Type>>;
+}
+
+/// A trait for putting evenness constraints on constants.
+///
+/// With the `incomplete` feature, only even constants implement it.
+/// Without it, odd constants implement it too and are rejected only when [Even::check] or [Even::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+pub trait Even: MayBeConstAT {
+    /// Evaluating this fails to compile if the value is a constant and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the constraint at runtime.
+    /// It always succeeds if the value is a constant.
+    fn check(&self) -> Result<(), PropertyViolation<Self::Type>>;
+}
+
+/// A trait for putting oddness constraints on constants.
+///
+/// With the `incomplete` feature, only odd constants implement it.
+/// Without it, even constants implement it too and are rejected only when [Odd::check] or [Odd::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+pub trait Odd: MayBeConstAT {
+    /// Evaluating this fails to compile if the value is a constant and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the constraint at runtime.
+    /// It always succeeds if the value is a constant.
+    fn check(&self) -> Result<(), PropertyViolation<Self::Type>>;
+}
+
+/// A trait for putting positive sign constraints on signed constants.
+///
+/// With the `incomplete` feature, only signed constants greater than zero implement it.
+/// Without it, every signed constant implements it, and zero or a negative constant is rejected only when [Positive::check_sign] or [Positive::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+pub trait Positive: MayBeConstAT {
+    /// Evaluating this fails to compile if the value is a constant and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the sign at runtime.
+    /// It always succeeds if the value is a constant.
+    fn check_sign(&self) -> Result<(), PropertyViolation<Self::Type>>;
+}
+
+/// A trait for putting non-negative sign constraints on signed constants.
+///
+/// With the `incomplete` feature, only signed constants greater than or equal to zero implement it.
+/// Without it, negative constants implement it too and are rejected only when [NonNegative::check_sign] or [NonNegative::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+///
+/// A non-negative constant can be converted into the constant of the matching unsigned type with [From].
+/// The target constant is not derived from the source at the type level:
+/// `From<Const<V>>` is implemented for every unsigned constant of the matching type,
+/// and a negative `V` or a target with another value fails to compile only when the conversion is evaluated.
+///
+/// # Example
+/// ```
+/// # #[cfg(all(feature = "isize", feature = "usize"))] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{ConstIsize, ConstUsize, MayBeConst, MayBeConstAT, NonNegative};
+///
+/// fn offset<T: MayBeConst<isize> + NonNegative>(offset: T) -> usize {
+///     offset.check_sign().unwrap();
+///     offset.value() as usize
+/// }
+///
+/// assert_eq!(offset(ConstIsize::<4>::new()), 4);
+/// assert_eq!(offset(4isize), 4);
+/// // offset(ConstIsize::<-4>::new()); <- Compile Error
+/// assert_eq!((-4isize).check_sign().unwrap_err().to_string(), "-4 is not non-negative");
+///
+/// let unsigned: ConstUsize<4> = ConstIsize::<4>::new().into();
+/// assert_eq!(unsigned.value(), 4);
+/// # }
+/// # #[cfg(not(all(feature = "isize", feature = "usize")))] fn test(){}
+/// # #[cfg(all(feature = "isize", feature = "usize"))] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait NonNegative: MayBeConstAT {
+    /// Evaluating this fails to compile if the value is a constant and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the sign at runtime.
+    /// It always succeeds if the value is a constant.
+    fn check_sign(&self) -> Result<(), PropertyViolation<Self::Type>>;
+}
+
+/// A trait for putting negative sign constraints on signed constants.
+///
+/// With the `incomplete` feature, only signed constants less than zero implement it.
+/// Without it, non-negative constants implement it too and are rejected only when [Negative::check_sign] or [Negative::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+pub trait Negative: MayBeConstAT {
+    /// Evaluating this fails to compile if the value is a constant and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the sign at runtime.
+    /// It always succeeds if the value is a constant.
+    fn check_sign(&self) -> Result<(), PropertyViolation<Self::Type>>;
+}
+
+/// The error type returned when a property constraint is not satisfied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PropertyViolation<T> {
+    value: T,
+    property: &'static str,
+}
+
+impl<T> PropertyViolation<T> {
+    /// The value which does not have the property.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for PropertyViolation<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} is not {}", self.value, self.property)
+    }
+}
+
+/// A trait for putting divisibility constraints on constants.
+/// Zero is the only multiple of zero.
+///
+/// With the `incomplete` feature, `Const<V>` implements it for a constant `M` only if `V` is a multiple of `M`.
+/// Without it, `Const<V>: MultipleOf<Const<M>>` holds for every pair,
+/// and a value which is not a multiple is rejected only when [MultipleOf::check] or [MultipleOf::ASSERT] is evaluated.
+/// See [the crate documentation](crate#constraints-on-constants).
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u32")] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{ConstU32, MayBeConst, MultipleOf};
+///
+/// fn blocks<T: MayBeConst<u32> + MultipleOf<ConstU32<512>>>(size: T) -> u32 {
+///     size.check(&ConstU32::<512>::new()).unwrap();
+///     size.value() / 512
+/// }
+///
+/// assert_eq!(blocks(ConstU32::<1024>::new()), 2);
+/// assert_eq!(blocks(1024u32), 2);
+/// // blocks(ConstU32::<1000>::new()); <- Compile Error
+///
+/// let error = 1000u32.check(&512).unwrap_err();
+/// assert_eq!(error.to_string(), "1000 is not a multiple of 512");
+/// # }
+/// # #[cfg(not(feature = "u32"))] fn test(){}
+/// # #[cfg(feature = "u32")] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait MultipleOf<M: MayBeConstAT>: MayBeConstAT<Type=M::Type> {
+    /// Evaluating this fails to compile if both values are constants and the constraint is not satisfied.
+    const ASSERT: () = ();
+    /// Check the constraint at runtime.
+    /// It always succeeds if both values are constants.
+    fn check(&self, m: &M) -> Result<(), NotMultipleOf<Self::Type>>;
+}
+
+/// The error type returned when a [MultipleOf] constraint is not satisfied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotMultipleOf<T> {
+    value: T,
+    divisor: T,
+}
+
+impl<T> NotMultipleOf<T> {
+    /// The value which is not a multiple of the divisor.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The divisor.
+    pub fn divisor(&self) -> &T {
+        &self.divisor
+    }
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for NotMultipleOf<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} is not a multiple of {}", self.value, self.divisor)
+    }
+}
+
+macro_rules! impl_property_check {
+    ($trait:ident :: $method:ident, const) => {
+        #[inline(always)]
+        fn $method(&self) -> Result<(), crate::PropertyViolation<Self::Type>> {
+            let () = <Self as crate::$trait>::ASSERT;
+            Ok(())
+        }
+    };
+    ($method:ident, $property:literal, $satisfied:path) => {
+        #[inline(always)]
+        fn $method(&self) -> Result<(), crate::PropertyViolation<Self::Type>> {
+            let value = crate::MayBeConstAT::value(self);
+            if $satisfied(value) {
+                Ok(())
+            } else {
+                Err(crate::PropertyViolation { value, property: $property })
+            }
+        }
+    };
+    ($trait:ident, const) => {
+        impl_property_check!($trait::check, const);
+    };
+    ($property:literal, $satisfied:path) => {
+        impl_property_check!(check, $property, $satisfied);
+    };
+}
+
+macro_rules! impl_multiple_of_check {
+    ($m:ty, const) => {
+        #[inline(always)]
+        fn check(&self, _: &$m) -> Result<(), crate::NotMultipleOf<Self::Type>> {
+            let () = <Self as crate::MultipleOf<$m>>::ASSERT;
+            Ok(())
+        }
+    };
+    ($m:ty) => {
+        #[inline(always)]
+        fn check(&self, m: &$m) -> Result<(), crate::NotMultipleOf<Self::Type>> {
+            let value = crate::MayBeConstAT::value(self);
+            let divisor = crate::MayBeConstAT::value(m);
+            if is_multiple_of(value, divisor) {
+                Ok(())
+            } else {
+                Err(crate::NotMultipleOf { value, divisor })
+            }
+        }
+    };
+}
+
 macro_rules! impl_stable {
     ($t:tt) => {
         impl crate::MayBeConstAT for $t {
@@ -103,14 +876,109 @@ macro_rules! impl_stable {
             }
         }
 
+        impl crate::Unify<$t> for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn unify(&self, rhs: &$t) -> Option<Self::Output> {
+                if *self == *rhs {
+                    Some(*self)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl crate::Lt<$t> for $t {
+            impl_ordering_check!(Lt, $t, <);
+        }
+        impl crate::Gt<$t> for $t {
+            impl_ordering_check!(Gt, $t, >);
+        }
+        impl crate::Leq<$t> for $t {
+            impl_ordering_check!(Leq, $t, <=);
+        }
+        impl crate::Geq<$t> for $t {
+            impl_ordering_check!(Geq, $t, >=);
+        }
+        impl crate::NotEquals<$t> for $t {
+            impl_ordering_check!(NotEquals::check_distinct, $t, !=);
+        }
+        impl crate::InRange<$t, $t> for $t {
+            impl_in_range_check!($t, $t);
+        }
+
         #[cfg(test)]
         mod test_stable {
             #[test]
             fn test_equals() {
+                #[allow(clippy::multiple_bound_locations)]
                 fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::Equals<B> {}
                 test::<$t, $t>();
             }
 
+            #[test]
+            fn test_unify() {
+                use crate::Unify;
+                assert_eq!((1 as $t).unify(&(1 as $t)), Some(1 as $t));
+                assert_eq!((1 as $t).unify(&(0 as $t)), None);
+            }
+
+            #[test]
+            fn test_ordering() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::Lt<B> + crate::Gt<B> + crate::Leq<B> + crate::Geq<B> {}
+                test::<$t, $t>();
+            }
+
+            #[test]
+            fn test_ordering_check() {
+                use crate::{Lt, Gt, Leq, Geq};
+                let zero = 0 as $t;
+                let one = 1 as $t;
+                assert!(Lt::check(&zero, &one).is_ok());
+                assert!(Lt::check(&one, &one).is_err());
+                assert!(Gt::check(&one, &zero).is_ok());
+                assert!(Gt::check(&one, &one).is_err());
+                assert!(Leq::check(&one, &one).is_ok());
+                assert!(Leq::check(&one, &zero).is_err());
+                assert!(Geq::check(&one, &one).is_ok());
+                assert!(Geq::check(&zero, &one).is_err());
+
+                let proof = Lt::check(&zero, &one).unwrap();
+                assert!(!proof.lhs_is_const());
+                assert!(!proof.rhs_is_const());
+                let error = Lt::check(&one, &zero).unwrap_err();
+                assert_eq!(*error.lhs(), one);
+                assert_eq!(*error.rhs(), zero);
+            }
+
+            #[test]
+            fn test_not_equals() {
+                use crate::NotEquals;
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, B: crate::MayBeConst<$t>>() where A: crate::NotEquals<B> {}
+                test::<$t, $t>();
+                assert!({0 as $t}.check_distinct(&{1 as $t}).is_ok());
+                let error = {1 as $t}.check_distinct(&{1 as $t}).unwrap_err();
+                assert_eq!(*error.lhs(), 1 as $t);
+                assert_eq!(*error.rhs(), 1 as $t);
+            }
+
+            #[test]
+            fn test_in_range() {
+                use crate::InRange;
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>, L: crate::MayBeConst<$t>, H: crate::MayBeConst<$t>>() where A: crate::InRange<L, H> {}
+                test::<$t, $t, $t>();
+                assert!({1 as $t}.check_in_range(&{1 as $t}, &{2 as $t}).is_ok());
+                assert!({2 as $t}.check_in_range(&{1 as $t}, &{2 as $t}).is_ok());
+                assert!({0 as $t}.check_in_range(&{1 as $t}, &{2 as $t}).is_err());
+                let error = {3 as $t}.check_in_range(&{1 as $t}, &{2 as $t}).unwrap_err();
+                assert_eq!(*error.value(), 3 as $t);
+                assert_eq!(*error.lo(), 1 as $t);
+                assert_eq!(*error.hi(), 2 as $t);
+            }
+
             #[test]
             fn test_is_const() {
                 assert!(!<$t as crate::MayBeConstAT>::IS_CONST);
@@ -118,3 +986,399 @@ macro_rules! impl_stable {
         }
     }
 }
+
+macro_rules! nonzero {
+    (usize) => { core::num::NonZeroUsize };
+    (u8) => { core::num::NonZeroU8 };
+    (u16) => { core::num::NonZeroU16 };
+    (u32) => { core::num::NonZeroU32 };
+    (u64) => { core::num::NonZeroU64 };
+    (u128) => { core::num::NonZeroU128 };
+    (isize) => { core::num::NonZeroIsize };
+    (i8) => { core::num::NonZeroI8 };
+    (i16) => { core::num::NonZeroI16 };
+    (i32) => { core::num::NonZeroI32 };
+    (i64) => { core::num::NonZeroI64 };
+    (i128) => { core::num::NonZeroI128 };
+}
+
+macro_rules! impl_prim_int {
+    ($t:tt, $signed:expr) => {
+        impl crate::PrimInt for $t {
+            const BITS: u32 = (core::mem::size_of::<$t>() * 8) as u32;
+            const MIN: $t = <$t>::MIN;
+            const MAX: $t = <$t>::MAX;
+            const SIGNED: bool = $signed;
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+            impl_prim_int!(@binary $t, checked_add, checked_sub, checked_mul, checked_div, checked_rem => Option<$t>);
+            impl_prim_int!(@binary $t, wrapping_add, wrapping_sub, wrapping_mul, saturating_add, saturating_sub, saturating_mul => $t);
+            #[inline(always)]
+            fn pow(self, exp: u32) -> $t {
+                <$t>::pow(self, exp)
+            }
+            impl_prim_int!(@unary $t, count_ones, count_zeros, leading_zeros, trailing_zeros);
+        }
+    };
+    (@binary $t:tt, $($method:ident),* => $output:ty) => {
+        $(
+            #[inline(always)]
+            fn $method(self, rhs: $t) -> $output {
+                <$t>::$method(self, rhs)
+            }
+        )*
+    };
+    (@unary $t:tt, $($method:ident),*) => {
+        $(
+            #[inline(always)]
+            fn $method(self) -> u32 {
+                <$t>::$method(self)
+            }
+        )*
+    };
+}
+
+macro_rules! impl_stable_props {
+    (@signed signed) => { true };
+    (@signed unsigned) => { false };
+    (char, $t:tt) => {};
+    ($kind:ident, $t:tt) => {
+        impl_prim_int!($t, impl_stable_props!(@signed $kind));
+
+        impl crate::MayBeConstNonZero for nonzero!($t) {
+            type Int = $t;
+            type NonZero = nonzero!($t);
+            const IS_CONST: bool = false;
+            #[inline(always)]
+            fn get(&self) -> $t {
+                <nonzero!($t)>::get(*self)
+            }
+            #[inline(always)]
+            fn nonzero(&self) -> nonzero!($t) {
+                *self
+            }
+        }
+
+        #[inline(always)]
+        fn is_nonzero(value: $t) -> bool {
+            value != 0
+        }
+
+        #[inline(always)]
+        fn is_power_of_two(value: $t) -> bool {
+            value > 0 && value & (value - 1) == 0
+        }
+
+        #[inline(always)]
+        fn is_even(value: $t) -> bool {
+            value % 2 == 0
+        }
+
+        #[inline(always)]
+        fn is_odd(value: $t) -> bool {
+            value % 2 != 0
+        }
+
+        #[inline(always)]
+        fn is_multiple_of(value: $t, divisor: $t) -> bool {
+            if divisor == 0 {
+                value == 0
+            } else {
+                value.wrapping_rem(divisor) == 0
+            }
+        }
+
+        impl crate::NonZero for $t {
+            impl_property_check!("nonzero", is_nonzero);
+        }
+        impl crate::PowerOfTwo for $t {
+            impl_property_check!("a power of two", is_power_of_two);
+        }
+        impl crate::Even for $t {
+            impl_property_check!("even", is_even);
+        }
+        impl crate::Odd for $t {
+            impl_property_check!("odd", is_odd);
+        }
+        impl crate::MultipleOf<$t> for $t {
+            impl_multiple_of_check!($t);
+        }
+
+        impl_stable_sign!($kind, $t);
+
+        #[cfg(test)]
+        mod test_stable_props {
+            #[test]
+            fn test_props() {
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>>() where A: crate::NonZero + crate::PowerOfTwo + crate::Even + crate::Odd + crate::MultipleOf<$t> {}
+                test::<$t>();
+
+                assert!(crate::NonZero::check(&{1 as $t}).is_ok());
+                assert!(crate::NonZero::check(&{0 as $t}).is_err());
+                assert!(crate::PowerOfTwo::check(&{1 as $t}).is_ok());
+                assert!(crate::PowerOfTwo::check(&{64 as $t}).is_ok());
+                assert!(crate::PowerOfTwo::check(&{0 as $t}).is_err());
+                assert!(crate::PowerOfTwo::check(&{12 as $t}).is_err());
+                assert!(crate::PowerOfTwo::check(&<$t>::MIN).is_err());
+                assert!(crate::Even::check(&{0 as $t}).is_ok());
+                assert!(crate::Even::check(&{3 as $t}).is_err());
+                assert!(crate::Odd::check(&{3 as $t}).is_ok());
+                assert!(crate::Odd::check(&{4 as $t}).is_err());
+
+                let error = crate::PowerOfTwo::check(&{12 as $t}).unwrap_err();
+                assert_eq!(*error.value(), 12 as $t);
+            }
+
+            #[test]
+            fn test_prim_int() {
+                use crate::PrimInt;
+                assert_eq!(<$t as PrimInt>::BITS, (core::mem::size_of::<$t>() * 8) as u32);
+                assert_eq!(PrimInt::checked_add(1 as $t, 2 as $t), Some(3 as $t));
+                assert_eq!(PrimInt::checked_add(<$t>::MAX, 1 as $t), None);
+                assert_eq!(PrimInt::count_ones(7 as $t), 3);
+                assert_eq!(<$t as PrimInt>::pow(2, 3), 8 as $t);
+            }
+
+            #[test]
+            fn test_nonzero() {
+                use crate::MayBeConstNonZero;
+                let value = <nonzero!($t)>::new(1 as $t).unwrap();
+                assert_eq!(value.get(), 1 as $t);
+                assert_eq!(MayBeConstNonZero::nonzero(&value), value);
+                assert!(!<nonzero!($t) as MayBeConstNonZero>::IS_CONST);
+            }
+
+            #[test]
+            fn test_multiple_of() {
+                use crate::MultipleOf;
+                assert!({12 as $t}.check(&{4 as $t}).is_ok());
+                assert!({0 as $t}.check(&{4 as $t}).is_ok());
+                assert!({0 as $t}.check(&{0 as $t}).is_ok());
+                assert!({4 as $t}.check(&{0 as $t}).is_err());
+                assert!(<$t>::MAX.check(&{1 as $t}).is_ok());
+                let error = {10 as $t}.check(&{4 as $t}).unwrap_err();
+                assert_eq!(*error.value(), 10 as $t);
+                assert_eq!(*error.divisor(), 4 as $t);
+            }
+        }
+    };
+}
+
+macro_rules! impl_stable_sign {
+    (unsigned, $t:tt) => {};
+    (signed, $t:tt) => {
+        #[inline(always)]
+        fn is_positive(value: $t) -> bool {
+            value > 0
+        }
+
+        #[inline(always)]
+        fn is_non_negative(value: $t) -> bool {
+            value >= 0
+        }
+
+        #[inline(always)]
+        fn is_negative(value: $t) -> bool {
+            value < 0
+        }
+
+        impl crate::Positive for $t {
+            impl_property_check!(check_sign, "positive", is_positive);
+        }
+        impl crate::NonNegative for $t {
+            impl_property_check!(check_sign, "non-negative", is_non_negative);
+        }
+        impl crate::Negative for $t {
+            impl_property_check!(check_sign, "negative", is_negative);
+        }
+
+        #[cfg(test)]
+        mod test_stable_sign {
+            #[test]
+            fn test_check_sign() {
+                use crate::{Negative, NonNegative, Positive};
+                #[allow(clippy::multiple_bound_locations)]
+                fn test<A: crate::MayBeConst<$t>>() where A: crate::Positive + crate::NonNegative + crate::Negative {}
+                test::<$t>();
+
+                assert!(Positive::check_sign(&{1 as $t}).is_ok());
+                assert!(Positive::check_sign(&{0 as $t}).is_err());
+                assert!(NonNegative::check_sign(&{0 as $t}).is_ok());
+                assert!(NonNegative::check_sign(&{-1 as $t}).is_err());
+                assert!(Negative::check_sign(&<$t>::MIN).is_ok());
+                assert!(Negative::check_sign(&{0 as $t}).is_err());
+
+                let error = Positive::check_sign(&{-1 as $t}).unwrap_err();
+                assert_eq!(*error.value(), -1 as $t);
+                assert_eq!(error.to_string(), "-1 is not positive");
+            }
+        }
+    };
+}
+
+/// A trait for lossless widening conversions of constant and non-constant values.
+/// A non-constant value is widened to a non-constant value.
+/// A constant is widened to a constant when the `incomplete` feature is enabled, and to a non-constant value otherwise.
+///
+/// # Example
+/// ```
+/// # #[cfg(all(feature = "u8", feature = "u32"))] fn test() {
+/// use partial_const::{MayBeConst, MayBeConstAT, Widen};
+///
+/// fn to_u32<T: MayBeConst<u8> + Widen<u32>>(i: T) -> u32 {
+///     i.widen().value()
+/// }
+///
+/// assert_eq!(to_u32(42u8), 42u32);
+/// # #[rustversion::since(1.51)] fn test_const() { assert_eq!(to_u32(partial_const::ConstU8::<42>::new()), 42u32); }
+/// # #[rustversion::not(since(1.51))] fn test_const() {}
+/// # test_const();
+/// # }
+/// # #[cfg(all(feature = "u8", feature = "u32"))] test();
+/// ```
+pub trait Widen<T>: MayBeConstAT {
+    /// The widened type, which is constant if `Self` is constant and the `incomplete` feature is enabled.
+    type Output: MayBeConst<T>;
+    fn widen(self) -> Self::Output;
+}
+
+macro_rules! impl_stable_widen {
+    ($from:tt, $to:tt) => {
+        impl crate::Widen<$to> for $from {
+            type Output = $to;
+            #[inline(always)]
+            fn widen(self) -> $to {
+                <$to>::from(self)
+            }
+        }
+    }
+}
+
+/// A trait for checked conversions of constant and non-constant integer values.
+/// For constants the conversion is checked at compile time and the converted value is returned directly
+/// (as a constant if the `incomplete` feature is enabled),
+/// otherwise [TryCastError] is returned if the value does not fit in `T`.
+///
+/// # Example
+/// ```
+/// # #[cfg(all(feature = "u16", feature = "u8"))] #[rustversion::since(1.57)] fn test() {
+/// use partial_const::{ConstU16, TryCastTo};
+///
+/// assert_eq!(TryCastTo::<u8>::try_cast_to(ConstU16::<42>::new()), 42u8);
+/// assert_eq!(TryCastTo::<u8>::try_cast_to(42u16), Ok(42u8));
+/// assert_eq!(TryCastTo::<u8>::try_cast_to(256u16).unwrap_err().to_string(), "256 is out of range for u8");
+/// // TryCastTo::<u8>::try_cast_to(ConstU16::<256>::new()); <- Compile Error
+/// # }
+/// # #[cfg(not(all(feature = "u16", feature = "u8")))] fn test(){}
+/// # #[cfg(all(feature = "u16", feature = "u8"))] #[rustversion::not(since(1.57))] fn test(){}
+/// # test();
+/// ```
+pub trait TryCastTo<T>: MayBeConstAT {
+    /// The converted value for constants, or `Result<T, TryCastError<Self::Type>>` otherwise.
+    type Output;
+    fn try_cast_to(self) -> Self::Output;
+}
+
+/// A trait for conversions of constant and non-constant integer values with the semantics of `as`.
+/// A non-constant value is converted to a non-constant value.
+/// A constant is converted to a constant when the `incomplete` feature is enabled, and to a non-constant value otherwise.
+///
+/// # Example
+/// ```
+/// # #[cfg(all(feature = "u16", feature = "u8"))] fn test() {
+/// use partial_const::AsCast;
+///
+/// assert_eq!(AsCast::<u8>::as_cast(42u16), 42u8);
+/// assert_eq!(AsCast::<u8>::as_cast(257u16), 1u8);
+/// # }
+/// # #[cfg(all(feature = "u16", feature = "u8"))] test();
+/// ```
+pub trait AsCast<T>: MayBeConstAT {
+    /// The converted type, which is constant if `Self` is constant and the `incomplete` feature is enabled.
+    type Output: MayBeConst<T>;
+    fn as_cast(self) -> Self::Output;
+}
+
+/// The error type returned when a value does not fit in the target type of [TryCastTo].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryCastError<T> {
+    value: T,
+    target: &'static str,
+}
+
+impl<T> TryCastError<T> {
+    /// The value which failed to be converted.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for TryCastError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} is out of range for {}", self.value, self.target)
+    }
+}
+
+macro_rules! impl_stable_cast {
+    ($from:tt, $to:tt) => {
+        impl crate::TryCastTo<$to> for $from {
+            type Output = Result<$to, crate::TryCastError<$from>>;
+            #[inline(always)]
+            fn try_cast_to(self) -> Self::Output {
+                <$to as core::convert::TryFrom<$from>>::try_from(self).map_err(|_| crate::TryCastError { value: self, target: stringify!($to) })
+            }
+        }
+
+        impl crate::AsCast<$to> for $from {
+            type Output = $to;
+            #[inline(always)]
+            fn as_cast(self) -> $to {
+                self as $to
+            }
+        }
+    }
+}
+
+/// The error type returned when a value does not match the value of a constant.
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "u32")] #[rustversion::since(1.51)] fn test() {
+/// let error = partial_const::ConstU32::<4096>::try_from_value(4095).unwrap_err();
+/// assert_eq!(*error.expected(), 4096);
+/// assert_eq!(*error.actual(), 4095);
+/// assert_eq!(error.to_string(), "expected 4096, found 4095");
+/// # }
+/// # #[cfg(not(feature = "u32"))] fn test(){}
+/// # #[cfg(feature = "u32")] #[rustversion::not(since(1.51))] fn test(){}
+/// # test();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValueMismatch<T> {
+    expected: T,
+    actual: T,
+}
+
+impl<T> ValueMismatch<T> {
+    /// Create an error from the value of the constant and the given value.
+    pub fn new(expected: T, actual: T) -> Self {
+        ValueMismatch { expected, actual }
+    }
+
+    /// The value of the constant.
+    pub fn expected(&self) -> &T {
+        &self.expected
+    }
+
+    /// The value which did not match.
+    pub fn actual(&self) -> &T {
+        &self.actual
+    }
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for ValueMismatch<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "expected {}, found {}", self.expected, self.actual)
+    }
+}